@@ -0,0 +1,427 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use object_store::aws::{AmazonS3, AmazonS3Builder};
+use object_store::azure::{MicrosoftAzure, MicrosoftAzureBuilder};
+use object_store::gcp::{GoogleCloudStorage, GoogleCloudStorageBuilder};
+use object_store::http::{HttpBuilder, HttpStore};
+use object_store::limit::LimitStore;
+use object_store::local::LocalFileSystem;
+use object_store::memory::InMemory;
+use object_store::path::Path;
+use object_store::prefix::PrefixObjectStore;
+use object_store::throttle::{ThrottleConfig, ThrottledStore};
+use object_store::{
+    ClientOptions, DynObjectStore, Error as ObjectStoreError, Result as ObjectStoreResult,
+    RetryConfig,
+};
+use url::Url;
+
+use crate::webhdfs::{WebHdfs, WebHdfsConfig};
+
+/// Parses a millisecond duration out of `options`, used for the `wait_*`
+/// [`ThrottleConfig`] knobs.
+fn duration_option(options: &HashMap<String, String>, key: &str) -> Option<Duration> {
+    options
+        .get(key)
+        .and_then(|value| value.parse::<u64>().ok())
+        .map(Duration::from_millis)
+}
+
+enum ObjectStoreKind {
+    Local,
+    InMemory,
+    S3,
+    Google,
+    Azure,
+    Http,
+    WebHdfs,
+}
+
+impl ObjectStoreKind {
+    pub fn parse_url(url: &Url) -> ObjectStoreResult<Self> {
+        match url.scheme() {
+            "file" => Ok(ObjectStoreKind::Local),
+            "memory" => Ok(ObjectStoreKind::InMemory),
+            "az" | "abfs" | "abfss" | "azure" | "wasb" | "adl" => Ok(ObjectStoreKind::Azure),
+            "s3" | "s3a" => Ok(ObjectStoreKind::S3),
+            "gs" => Ok(ObjectStoreKind::Google),
+            "https" => {
+                let host = url.host_str().unwrap_or_default();
+                if host.contains("amazonaws.com") {
+                    Ok(ObjectStoreKind::S3)
+                } else if host.contains("dfs.core.windows.net")
+                    || host.contains("blob.core.windows.net")
+                {
+                    Ok(ObjectStoreKind::Azure)
+                } else {
+                    Ok(ObjectStoreKind::Http)
+                }
+            }
+            "http" => Ok(ObjectStoreKind::Http),
+            "webhdfs" | "webhdfss" | "hdfs" => Ok(ObjectStoreKind::WebHdfs),
+            _ => Err(ObjectStoreError::NotImplemented),
+        }
+    }
+}
+
+enum ObjectStoreImpl {
+    Local(LocalFileSystem),
+    InMemory(InMemory),
+    Azrue(MicrosoftAzure),
+    S3(AmazonS3),
+    Gcp(GoogleCloudStorage),
+    Http(HttpStore),
+    WebHdfs(WebHdfs),
+}
+
+impl ObjectStoreImpl {
+    pub fn into_prefix(self, prefix: Path) -> Arc<DynObjectStore> {
+        match self {
+            ObjectStoreImpl::Local(store) => Arc::new(PrefixObjectStore::new(store, prefix)),
+            ObjectStoreImpl::InMemory(store) => Arc::new(PrefixObjectStore::new(store, prefix)),
+            ObjectStoreImpl::Azrue(store) => Arc::new(PrefixObjectStore::new(store, prefix)),
+            ObjectStoreImpl::S3(store) => Arc::new(PrefixObjectStore::new(store, prefix)),
+            ObjectStoreImpl::Gcp(store) => Arc::new(PrefixObjectStore::new(store, prefix)),
+            ObjectStoreImpl::Http(store) => Arc::new(PrefixObjectStore::new(store, prefix)),
+            ObjectStoreImpl::WebHdfs(store) => Arc::new(PrefixObjectStore::new(store, prefix)),
+        }
+    }
+
+    pub fn into_store(self) -> Arc<DynObjectStore> {
+        match self {
+            ObjectStoreImpl::Local(store) => Arc::new(store),
+            ObjectStoreImpl::InMemory(store) => Arc::new(store),
+            ObjectStoreImpl::Azrue(store) => Arc::new(store),
+            ObjectStoreImpl::S3(store) => Arc::new(store),
+            ObjectStoreImpl::Gcp(store) => Arc::new(store),
+            ObjectStoreImpl::Http(store) => Arc::new(store),
+            ObjectStoreImpl::WebHdfs(store) => Arc::new(store),
+        }
+    }
+}
+
+/// Builds the concrete [`DynObjectStore`] backing [`crate::PyObjectStore`]/
+/// [`crate::ArrowFileSystemHandler`] from a root url plus a flat
+/// `options`/`client_options`/`retry_config` bag, inferring the backend
+/// (local, in-memory, S3, GCS, Azure, HTTP/WebDAV, WebHDFS) from the url's
+/// scheme or host, or from `options["scheme"]`/[`Self::with_scheme`] when the
+/// hostname-based inference isn't enough (e.g. a MinIO or R2 endpoint).
+#[derive(Debug, Clone)]
+pub struct ObjectStoreBuilder {
+    url: String,
+    prefix: Option<Path>,
+    path_as_prefix: bool,
+    options: HashMap<String, String>,
+    client_options: Option<ClientOptions>,
+    retry_config: Option<RetryConfig>,
+    /// Forces the store kind instead of inferring it from the url, e.g. `"s3"`
+    /// so that a MinIO / R2 endpoint that doesn't match any known cloud
+    /// hostname is still routed to the S3 backend.
+    scheme: Option<String>,
+    /// Caps the number of requests in flight against the built store via
+    /// [`LimitStore`].
+    max_concurrent_requests: Option<usize>,
+    /// Injects artificial latency into every call via [`ThrottledStore`], for
+    /// simulating a slow backend in tests.
+    throttle_config: Option<ThrottleConfig>,
+}
+
+impl ObjectStoreBuilder {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            prefix: None,
+            path_as_prefix: false,
+            options: Default::default(),
+            client_options: None,
+            retry_config: None,
+            scheme: None,
+            max_concurrent_requests: None,
+            throttle_config: None,
+        }
+    }
+
+    pub fn with_options<I: IntoIterator<Item = (impl Into<String>, impl Into<String>)>>(
+        mut self,
+        options: I,
+    ) -> Self {
+        self.options
+            .extend(options.into_iter().map(|(k, v)| (k.into(), v.into())));
+        self
+    }
+
+    pub fn with_option(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.options.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn with_prefix(mut self, prefix: impl Into<Path>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    pub fn with_path_as_prefix(mut self, path_as_prefix: bool) -> Self {
+        self.path_as_prefix = path_as_prefix;
+        self
+    }
+
+    pub fn with_client_options(mut self, options: ClientOptions) -> Self {
+        self.client_options = Some(options);
+        self
+    }
+
+    pub fn with_retry_config(mut self, retry_config: RetryConfig) -> Self {
+        self.retry_config = Some(retry_config);
+        self
+    }
+
+    /// Wraps the built store in a [`LimitStore`] that acquires one of `max`
+    /// semaphore permits around every trait call, bounding the number of
+    /// requests in flight at once.
+    pub fn with_max_concurrent_requests(mut self, max: usize) -> Self {
+        self.max_concurrent_requests = Some(max);
+        self
+    }
+
+    /// Wraps the built store in a [`ThrottledStore`] configured with
+    /// `config`, sleeping before each call to simulate a slow backend.
+    pub fn with_throttle_config(mut self, config: ThrottleConfig) -> Self {
+        self.throttle_config = Some(config);
+        self
+    }
+
+    /// Forces the store kind used to build the inner store, bypassing the
+    /// hostname-based inference in [`ObjectStoreKind::parse_url`]. Use this to
+    /// point an S3-compatible endpoint (MinIO, Cloudflare R2, Ceph) at the S3
+    /// backend regardless of the url's hostname, e.g. `with_scheme("s3")`.
+    pub fn with_scheme(mut self, scheme: impl Into<String>) -> Self {
+        self.scheme = Some(scheme.into());
+        self
+    }
+
+    /// Sets the `endpoint` option (custom S3/GCS/Azure endpoint, e.g. a MinIO
+    /// or R2 url).
+    pub fn with_endpoint(self, endpoint: impl Into<String>) -> Self {
+        self.with_option("endpoint", endpoint)
+    }
+
+    /// Sets the `region` option.
+    pub fn with_region(self, region: impl Into<String>) -> Self {
+        self.with_option("region", region)
+    }
+
+    /// Sets the `allow_http` option, permitting plain-HTTP endpoints such as a
+    /// local MinIO instance.
+    pub fn with_allow_http(self, allow_http: bool) -> Self {
+        self.with_option("allow_http", allow_http.to_string())
+    }
+
+    /// Sets the `virtual_hosted_style_request` option. When `true`, the bucket
+    /// name is expected to be part of the host rather than the path, which R2
+    /// and some MinIO deployments require.
+    pub fn with_virtual_hosted_style(self, virtual_hosted_style: bool) -> Self {
+        self.with_option(
+            "virtual_hosted_style_request",
+            virtual_hosted_style.to_string(),
+        )
+    }
+
+    pub fn build(mut self) -> ObjectStoreResult<Arc<DynObjectStore>> {
+        let maybe_url = Url::parse(&self.url);
+        let url =
+            match maybe_url {
+                Ok(url) => Ok(url),
+                Err(url::ParseError::RelativeUrlWithoutBase) => {
+                    let abs_path = std::fs::canonicalize(std::path::PathBuf::from(&self.url))
+                        .map_err(|err| ObjectStoreError::Generic {
+                            store: "Generic",
+                            source: Box::new(err),
+                        })?;
+                    Url::parse(&format!("file://{}", abs_path.to_str().unwrap())).map_err(|err| {
+                        ObjectStoreError::Generic {
+                            store: "Generic",
+                            source: Box::new(err),
+                        }
+                    })
+                }
+                Err(err) => Err(ObjectStoreError::Generic {
+                    store: "Generic",
+                    source: Box::new(err),
+                }),
+            }?;
+        // The forced scheme can come from `with_scheme` (Rust callers) or
+        // from an `options["scheme"]` entry (the Python binding, which only
+        // ever forwards a flat options dict), so either path can route a
+        // MinIO/R2 endpoint to the S3 backend regardless of hostname.
+        let forced_scheme = self
+            .scheme
+            .clone()
+            .or_else(|| self.options.get("scheme").cloned());
+        let kind = match forced_scheme.as_deref() {
+            Some("s3") | Some("s3a") => ObjectStoreKind::S3,
+            Some("az") | Some("abfs") | Some("abfss") | Some("azure") | Some("wasb")
+            | Some("adl") => ObjectStoreKind::Azure,
+            Some("gs") => ObjectStoreKind::Google,
+            Some("http") | Some("https") => ObjectStoreKind::Http,
+            Some("file") => ObjectStoreKind::Local,
+            Some("memory") => ObjectStoreKind::InMemory,
+            Some("webhdfs") | Some("webhdfss") | Some("hdfs") => ObjectStoreKind::WebHdfs,
+            Some(other) => {
+                return Err(ObjectStoreError::Generic {
+                    store: "Generic",
+                    source: format!("unknown forced scheme '{other}'").into(),
+                })
+            }
+            None => ObjectStoreKind::parse_url(&url)?,
+        };
+        let root_store = match kind {
+            ObjectStoreKind::Local => ObjectStoreImpl::Local(LocalFileSystem::new()),
+            ObjectStoreKind::InMemory => ObjectStoreImpl::InMemory(InMemory::new()),
+            ObjectStoreKind::Azure => {
+                let store = MicrosoftAzureBuilder::new()
+                    .with_url(url.clone())
+                    .try_with_options(&self.options)?
+                    .with_client_options(self.client_options.clone().unwrap_or_default())
+                    .with_retry(self.retry_config.clone().unwrap_or_default())
+                    .build()
+                    .or_else(|_| {
+                        MicrosoftAzureBuilder::from_env()
+                            .with_url(url.clone())
+                            .try_with_options(&self.options)?
+                            .with_client_options(self.client_options.clone().unwrap_or_default())
+                            .with_retry(self.retry_config.clone().unwrap_or_default())
+                            .build()
+                    })?;
+                ObjectStoreImpl::Azrue(store)
+            }
+            ObjectStoreKind::S3 => {
+                let store = AmazonS3Builder::new()
+                    .with_url(url.clone())
+                    .try_with_options(&self.options)?
+                    .with_client_options(self.client_options.clone().unwrap_or_default())
+                    .with_retry(self.retry_config.clone().unwrap_or_default())
+                    .build()
+                    .or_else(|_| {
+                        AmazonS3Builder::from_env()
+                            .with_url(url.clone())
+                            .try_with_options(&self.options)?
+                            .with_client_options(self.client_options.unwrap_or_default())
+                            .with_retry(self.retry_config.unwrap_or_default())
+                            .build()
+                    })?;
+                ObjectStoreImpl::S3(store)
+            }
+            ObjectStoreKind::Google => {
+                let store = GoogleCloudStorageBuilder::new()
+                    .with_url(url.clone())
+                    .try_with_options(&self.options)?
+                    .with_client_options(self.client_options.clone().unwrap_or_default())
+                    .with_retry(self.retry_config.clone().unwrap_or_default())
+                    .build()
+                    .or_else(|_| {
+                        GoogleCloudStorageBuilder::from_env()
+                            .with_url(url.clone())
+                            .try_with_options(&self.options)?
+                            .with_client_options(self.client_options.unwrap_or_default())
+                            .with_retry(self.retry_config.unwrap_or_default())
+                            .build()
+                    })?;
+                ObjectStoreImpl::Gcp(store)
+            }
+            ObjectStoreKind::Http => {
+                let store = HttpBuilder::new()
+                    .with_url(url.clone())
+                    .with_client_options(self.client_options.clone().unwrap_or_default())
+                    .with_retry(self.retry_config.clone().unwrap_or_default())
+                    .build()?;
+                ObjectStoreImpl::Http(store)
+            }
+            ObjectStoreKind::WebHdfs => {
+                let mut namenode = url.clone();
+                let scheme = if url.scheme() == "webhdfss" {
+                    "https"
+                } else {
+                    "http"
+                };
+                namenode
+                    .set_scheme(scheme)
+                    .map_err(|_| ObjectStoreError::Generic {
+                        store: "Generic",
+                        source: "failed to normalize WebHDFS namenode scheme".into(),
+                    })?;
+                namenode.set_path("");
+                let host_remap = self
+                    .options
+                    .iter()
+                    .filter_map(|(key, value)| {
+                        key.strip_prefix("datanode.map.")
+                            .map(|host| (host.to_string(), value.clone()))
+                    })
+                    .collect();
+                let config = WebHdfsConfig {
+                    user_name: self.options.get("user.name").cloned(),
+                    doas: self.options.get("doas").cloned(),
+                    delegation_token: self.options.get("delegation_token").cloned(),
+                    host_remap,
+                };
+                ObjectStoreImpl::WebHdfs(WebHdfs::new(namenode, config))
+            }
+        };
+
+        if self.path_as_prefix && !url.path().is_empty() && self.prefix.is_none() {
+            self.prefix = Some(Path::from(url.path()))
+        }
+
+        let mut store: Arc<DynObjectStore> = if let Some(prefix) = self.prefix {
+            root_store.into_prefix(prefix)
+        } else {
+            root_store.into_store()
+        };
+
+        let throttle_config = self.throttle_config.or_else(|| {
+            let mut config = ThrottleConfig::default();
+            let mut any = false;
+            if let Some(wait) = duration_option(&self.options, "wait_get_per_call") {
+                config.wait_get_per_call = wait;
+                any = true;
+            }
+            if let Some(wait) = duration_option(&self.options, "wait_get_per_byte") {
+                config.wait_get_per_byte = wait;
+                any = true;
+            }
+            if let Some(wait) = duration_option(&self.options, "wait_list_per_call") {
+                config.wait_list_per_call = wait;
+                any = true;
+            }
+            if let Some(wait) = duration_option(&self.options, "wait_list_per_entry") {
+                config.wait_list_per_entry = wait;
+                any = true;
+            }
+            if let Some(wait) = duration_option(&self.options, "wait_put_per_call") {
+                config.wait_put_per_call = wait;
+                any = true;
+            }
+            if let Some(wait) = duration_option(&self.options, "wait_delete_per_call") {
+                config.wait_delete_per_call = wait;
+                any = true;
+            }
+            any.then_some(config)
+        });
+        if let Some(throttle_config) = throttle_config {
+            store = Arc::new(ThrottledStore::new(store, throttle_config));
+        }
+
+        let max_concurrent_requests = self.max_concurrent_requests.or_else(|| {
+            self.options
+                .get("max_concurrent_requests")
+                .and_then(|value| value.parse::<usize>().ok())
+        });
+        if let Some(max_concurrent_requests) = max_concurrent_requests {
+            store = Arc::new(LimitStore::new(store, max_concurrent_requests));
+        }
+
+        Ok(store)
+    }
+}
@@ -5,13 +5,112 @@ use crate::builder::ObjectStoreBuilder;
 use crate::utils::{delete_dir, walk_tree};
 use crate::{ObjectStoreError, PyClientOptions};
 
+use bytes::Bytes;
+use futures::stream::{self, StreamExt, TryStreamExt};
 use object_store::path::Path;
-use object_store::{DynObjectStore, Error as InnerObjectStoreError, ListResult, MultipartUpload};
+use object_store::{
+    Attribute, Attributes, DynObjectStore, Error as InnerObjectStoreError, ListResult,
+    MultipartUpload, PutMultipartOpts, PutOptions, TagSet,
+};
 use pyo3::exceptions::{PyNotImplementedError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::{IntoPyDict, PyBytes};
+use pyo3::types::{IntoPyDict, PyBytes, PyList};
 use tokio::runtime::Runtime;
 
+/// `metadata` keys under this prefix become object tags (e.g. S3/GCS object
+/// tagging) rather than custom metadata.
+const TAG_PREFIX: &str = "tags.";
+
+/// `metadata` keys under this prefix are stripped of the prefix and passed
+/// through as store-native custom metadata, same as an unprefixed key.
+const METADATA_PREFIX: &str = "metadata.";
+
+/// Splits the `metadata` map accepted by
+/// [`ArrowFileSystemHandler::open_output_stream`] into object_store
+/// [`PutMultipartOpts`]: well-known keys (`content_type`, `content_encoding`,
+/// `content_disposition`, `cache_control`) become [`Attribute`]s, keys
+/// prefixed with [`TAG_PREFIX`] become tags, and everything else (including
+/// [`METADATA_PREFIX`]-prefixed keys, with the prefix stripped) is passed
+/// through as custom metadata.
+fn build_put_opts(metadata: Option<HashMap<String, String>>) -> PutMultipartOpts {
+    let mut attributes = Attributes::new();
+    let mut tags = TagSet::default();
+
+    for (key, value) in metadata.into_iter().flatten() {
+        match key.as_str() {
+            "content_type" => {
+                attributes.insert(Attribute::ContentType, value.into());
+            }
+            "content_encoding" => {
+                attributes.insert(Attribute::ContentEncoding, value.into());
+            }
+            "content_disposition" => {
+                attributes.insert(Attribute::ContentDisposition, value.into());
+            }
+            "cache_control" => {
+                attributes.insert(Attribute::CacheControl, value.into());
+            }
+            key if key.starts_with(TAG_PREFIX) => {
+                tags.push(&key[TAG_PREFIX.len()..], &value);
+            }
+            key if key.starts_with(METADATA_PREFIX) => {
+                attributes.insert(
+                    Attribute::Metadata(key[METADATA_PREFIX.len()..].to_string().into()),
+                    value.into(),
+                );
+            }
+            key => {
+                attributes.insert(Attribute::Metadata(key.to_string().into()), value.into());
+            }
+        }
+    }
+
+    PutMultipartOpts {
+        attributes,
+        tags,
+        ..Default::default()
+    }
+}
+
+/// Default cap on the number of paths [`ArrowFileSystemHandler::get_file_info`]
+/// resolves (`list_with_delimiter` plus `head`) at once. `get_file_info_selector`
+/// lists through [`walk_tree`], which applies its own cap.
+const DEFAULT_LIST_CONCURRENCY: usize = 16;
+
+/// What [`resolve_file_info`] found at a path, with no Python types touched
+/// so the concurrent resolution stage can run entirely off the GIL.
+enum PathInfo {
+    File { size: i64, mtime_ns: i64 },
+    Directory,
+    NotFound,
+}
+
+/// Classifies a single path as a file, directory, or missing, without
+/// holding the GIL -- the Python-facing caller turns the result into a
+/// `pyarrow.fs.FileInfo` afterward.
+async fn resolve_file_info(
+    store: Arc<DynObjectStore>,
+    path: Path,
+) -> Result<(String, PathInfo), InnerObjectStoreError> {
+    let listed = store.list_with_delimiter(Some(&path)).await?;
+    // TODO is there a better way to figure out if we are in a directory?
+    if !listed.objects.is_empty() || !listed.common_prefixes.is_empty() {
+        return Ok((path.to_string(), PathInfo::Directory));
+    }
+
+    match store.head(&path).await {
+        Ok(meta) => Ok((
+            meta.location.to_string(),
+            PathInfo::File {
+                size: meta.size as i64,
+                mtime_ns: meta.last_modified.timestamp_nanos_opt().unwrap(),
+            },
+        )),
+        Err(InnerObjectStoreError::NotFound { .. }) => Ok((path.to_string(), PathInfo::NotFound)),
+        Err(err) => Err(err),
+    }
+}
+
 #[pyclass(subclass, weakref)]
 #[derive(Debug, Clone)]
 pub struct ArrowFileSystemHandler {
@@ -89,9 +188,11 @@ impl ArrowFileSystemHandler {
         Ok(format!("{:?}", self) == format!("{:?}", other))
     }
 
+    #[pyo3(signature = (paths, max_concurrency = DEFAULT_LIST_CONCURRENCY))]
     fn get_file_info<'py>(
         &self,
         paths: Vec<String>,
+        max_concurrency: usize,
         py: Python<'py>,
     ) -> PyResult<Vec<Bound<'py, pyo3::PyAny>>> {
         let fs = PyModule::import_bound(py, "pyarrow.fs")?;
@@ -105,53 +206,39 @@ impl ArrowFileSystemHandler {
             )
         };
 
-        let mut infos = Vec::new();
-        for file_path in paths {
-            let path = Path::from(file_path);
-            let listed = self
-                .rt
-                .block_on(self.inner.list_with_delimiter(Some(&path)))
-                .map_err(ObjectStoreError::from)?;
+        // Resolve every path concurrently (bounded by `max_concurrency`) on a
+        // worker stage that touches no Python, preserving input order so the
+        // FileInfo objects built below still line up with `paths`.
+        let resolved = self
+            .rt
+            .block_on(async {
+                stream::iter(paths.into_iter().map(Path::from))
+                    .map(|path| {
+                        let store = self.inner.clone();
+                        async move { resolve_file_info(store, path).await }
+                    })
+                    .buffered(max_concurrency)
+                    .try_collect::<Vec<_>>()
+                    .await
+            })
+            .map_err(ObjectStoreError::from)?;
 
-            // TODO is there a better way to figure out if we are in a directory?
-            if listed.objects.is_empty() && listed.common_prefixes.is_empty() {
-                let maybe_meta = self.rt.block_on(self.inner.head(&path));
-                match maybe_meta {
-                    Ok(meta) => {
-                        let kwargs = HashMap::from([
-                            ("size", meta.size as i64),
-                            (
-                                "mtime_ns",
-                                meta.last_modified.timestamp_nanos_opt().unwrap(),
-                            ),
-                        ]);
-                        infos.push(to_file_info(
-                            meta.location.to_string(),
-                            file_types.getattr("File")?,
-                            kwargs,
-                        )?);
-                    }
-                    Err(object_store::Error::NotFound { .. }) => {
-                        infos.push(to_file_info(
-                            path.to_string(),
-                            file_types.getattr("NotFound")?,
-                            HashMap::new(),
-                        )?);
-                    }
-                    Err(err) => {
-                        return Err(ObjectStoreError::from(err).into());
-                    }
+        resolved
+            .into_iter()
+            .map(|(loc, info)| match info {
+                PathInfo::File { size, mtime_ns } => to_file_info(
+                    loc,
+                    file_types.getattr("File")?,
+                    HashMap::from([("size", size), ("mtime_ns", mtime_ns)]),
+                ),
+                PathInfo::Directory => {
+                    to_file_info(loc, file_types.getattr("Directory")?, HashMap::new())
                 }
-            } else {
-                infos.push(to_file_info(
-                    path.to_string(),
-                    file_types.getattr("Directory")?,
-                    HashMap::new(),
-                )?);
-            }
-        }
-
-        Ok(infos)
+                PathInfo::NotFound => {
+                    to_file_info(loc, file_types.getattr("NotFound")?, HashMap::new())
+                }
+            })
+            .collect()
     }
 
     #[pyo3(signature = (base_dir, allow_not_found = false, recursive = false))]
@@ -194,42 +281,38 @@ impl ArrowFileSystemHandler {
         }
         .map_err(ObjectStoreError::from)?;
 
-        let mut infos = vec![];
-        infos.extend(
-            list_result
-                .common_prefixes
-                .into_iter()
-                .map(|p| {
-                    to_file_info(
-                        p.to_string(),
-                        file_types.getattr("Directory")?,
-                        HashMap::new(),
-                    )
-                })
-                .collect::<Result<Vec<_>, _>>()?,
-        );
-        infos.extend(
-            list_result
-                .objects
-                .into_iter()
-                .map(|meta| {
-                    let kwargs = HashMap::from([
-                        ("size", meta.size as i64),
-                        (
-                            "mtime_ns",
-                            meta.last_modified.timestamp_nanos_opt().unwrap(),
-                        ),
-                    ]);
-                    to_file_info(
-                        meta.location.to_string(),
-                        file_types.getattr("File")?,
-                        kwargs,
-                    )
-                })
-                .collect::<Result<Vec<_>, _>>()?,
-        );
-
-        Ok(infos)
+        // Turn the listing into (location, PathInfo) pairs first -- no
+        // Python involved -- then build the FileInfo objects afterward while
+        // holding the GIL, mirroring `get_file_info`.
+        let resolved = list_result
+            .common_prefixes
+            .into_iter()
+            .map(|p| (p.to_string(), PathInfo::Directory))
+            .chain(list_result.objects.into_iter().map(|meta| {
+                (
+                    meta.location.to_string(),
+                    PathInfo::File {
+                        size: meta.size as i64,
+                        mtime_ns: meta.last_modified.timestamp_nanos_opt().unwrap(),
+                    },
+                )
+            }));
+
+        resolved
+            .map(|(loc, info)| match info {
+                PathInfo::File { size, mtime_ns } => to_file_info(
+                    loc,
+                    file_types.getattr("File")?,
+                    HashMap::from([("size", size), ("mtime_ns", mtime_ns)]),
+                ),
+                PathInfo::Directory => {
+                    to_file_info(loc, file_types.getattr("Directory")?, HashMap::new())
+                }
+                PathInfo::NotFound => {
+                    to_file_info(loc, file_types.getattr("NotFound")?, HashMap::new())
+                }
+            })
+            .collect()
     }
 
     fn move_file(&self, src: String, dest: String) -> PyResult<()> {
@@ -255,21 +338,23 @@ impl ArrowFileSystemHandler {
         Ok(file)
     }
 
-    #[pyo3(signature = (path, metadata = None))]
+    #[pyo3(signature = (path, metadata = None, buffer_size = None, single_shot_threshold = None))]
     fn open_output_stream(
         &self,
         path: String,
-        #[allow(unused)] metadata: Option<HashMap<String, String>>,
+        metadata: Option<HashMap<String, String>>,
+        buffer_size: Option<usize>,
+        single_shot_threshold: Option<usize>,
     ) -> PyResult<ObjectOutputStream> {
         let path = Path::from(path);
-        let file = self
-            .rt
-            .block_on(ObjectOutputStream::try_new(
-                self.rt.clone(),
-                self.inner.clone(),
-                path,
-            ))
-            .map_err(ObjectStoreError::from)?;
+        let file = ObjectOutputStream::new(
+            self.rt.clone(),
+            self.inner.clone(),
+            path,
+            buffer_size.unwrap_or(DEFAULT_PART_SIZE),
+            single_shot_threshold.unwrap_or(DEFAULT_SINGLE_SHOT_THRESHOLD),
+            build_put_opts(metadata),
+        );
         Ok(file)
     }
 
@@ -278,8 +363,13 @@ impl ArrowFileSystemHandler {
     }
 }
 
+/// Default read-ahead block size for [`ObjectInputFile::read`]/`readline`: a
+/// cache miss fetches at least this many bytes (clamped to `content_length`)
+/// in one `get_range` so subsequent small, sequential reads/lines are served
+/// from memory instead of issuing one GET each.
+const DEFAULT_READ_AHEAD_SIZE: usize = 1024 * 1024;
+
 // TODO the C++ implementation track an internal lock on all random access files, DO we need this here?
-// TODO add buffer to store data ...
 #[pyclass(weakref)]
 #[derive(Debug, Clone)]
 pub struct ObjectInputFile {
@@ -292,6 +382,9 @@ pub struct ObjectInputFile {
     pos: i64,
     #[pyo3(get)]
     mode: String,
+    /// Read-ahead window: the start offset and bytes of the most recent
+    /// `get_range` fetch, reused by reads/readlines that fall inside it.
+    buffer: Option<(i64, Bytes)>,
 }
 
 impl ObjectInputFile {
@@ -314,9 +407,71 @@ impl ObjectInputFile {
             closed: false,
             pos: 0,
             mode: "rb".into(),
+            buffer: None,
         })
     }
 
+    /// Returns the bytes of `range` from the read-ahead window if it's fully
+    /// contained within the buffered window.
+    fn buffered(&self, range: &std::ops::Range<usize>) -> Option<Bytes> {
+        let (start, buf) = self.buffer.as_ref()?;
+        let start = *start as usize;
+        let end = start + buf.len();
+        if range.start >= start && range.end <= end {
+            Some(buf.slice(range.start - start..range.end - start))
+        } else {
+            None
+        }
+    }
+
+    /// Serves `range` from the read-ahead buffer if it's covered, otherwise
+    /// fetches at least a [`DEFAULT_READ_AHEAD_SIZE`] window starting at
+    /// `range.start` (clamped to EOF) in a single `get_range`, caches it, and
+    /// serves from that.
+    fn read_range(&mut self, range: std::ops::Range<usize>) -> Result<Bytes, ObjectStoreError> {
+        if let Some(cached) = self.buffered(&range) {
+            return Ok(cached);
+        }
+        let fetch_len = usize::max(range.end - range.start, DEFAULT_READ_AHEAD_SIZE);
+        let fetch_end = usize::min(range.start + fetch_len, self.content_length as usize);
+        let fetch_range = range.start..fetch_end;
+        let buf = self
+            .rt
+            .block_on(self.store.get_range(&self.path, fetch_range.clone()))?;
+        let result = buf.slice(0..range.end - range.start);
+        self.buffer = Some((fetch_range.start as i64, buf));
+        Ok(result)
+    }
+
+    /// Core of [`Self::readline`]/[`Self::readlines`]: scans forward from
+    /// `pos` for `\n`, refilling the read-ahead buffer one block at a time,
+    /// until a newline, EOF, or `size` bytes have been consumed. Advances
+    /// `pos` past the bytes returned, including the trailing newline.
+    fn readline_bytes(&mut self, size: Option<i64>) -> Result<Vec<u8>, ObjectStoreError> {
+        let limit = size
+            .map(|size| i64::min(self.pos + size, self.content_length))
+            .unwrap_or(self.content_length) as usize;
+
+        let mut line = Vec::new();
+        while (self.pos as usize) < limit {
+            let pos = self.pos as usize;
+            let probe_end = usize::min(pos + DEFAULT_READ_AHEAD_SIZE, limit);
+            let chunk = self.read_range(pos..probe_end)?;
+            match chunk.iter().position(|&b| b == b'\n') {
+                Some(idx) => {
+                    line.extend_from_slice(&chunk[..=idx]);
+                    self.pos += (idx + 1) as i64;
+                    break;
+                }
+                None => {
+                    line.extend_from_slice(&chunk);
+                    self.pos += chunk.len() as i64;
+                }
+            }
+        }
+        Ok(line)
+    }
+
     fn check_closed(&self) -> Result<(), ObjectStoreError> {
         if self.closed {
             return Err(ObjectStoreError::Common(
@@ -422,11 +577,9 @@ impl ObjectInputFile {
         let nbytes = (range.end - range.start) as i64;
         self.pos += nbytes;
         let data = if nbytes > 0 {
-            self.rt
-                .block_on(self.store.get_range(&self.path, range))
-                .map_err(ObjectStoreError::from)?
+            self.read_range(range).map_err(ObjectStoreError::from)?
         } else {
-            "".into()
+            Bytes::new()
         };
         Python::with_gil(|py| Ok(PyBytes::new_bound(py, data.as_ref()).into_py(py)))
     }
@@ -439,25 +592,68 @@ impl ObjectInputFile {
         Err(PyNotImplementedError::new_err("'truncate' not implemented"))
     }
 
-    fn readline(&self, _size: Option<i64>) -> PyResult<()> {
-        Err(PyNotImplementedError::new_err("'readline' not implemented"))
+    #[pyo3(signature = (size = None))]
+    fn readline(&mut self, size: Option<i64>) -> PyResult<Py<PyAny>> {
+        self.check_closed()?;
+        let line = self.readline_bytes(size).map_err(ObjectStoreError::from)?;
+        Python::with_gil(|py| Ok(PyBytes::new_bound(py, &line).into_py(py)))
     }
 
-    fn readlines(&self, _hint: Option<i64>) -> PyResult<()> {
-        Err(PyNotImplementedError::new_err(
-            "'readlines' not implemented",
-        ))
+    #[pyo3(signature = (hint = None))]
+    fn readlines(&mut self, hint: Option<i64>) -> PyResult<Py<PyAny>> {
+        self.check_closed()?;
+        let mut lines = Vec::new();
+        let mut total = 0usize;
+        while self.pos < self.content_length {
+            let line = self.readline_bytes(None).map_err(ObjectStoreError::from)?;
+            if line.is_empty() {
+                break;
+            }
+            total += line.len();
+            lines.push(line);
+            if hint.is_some_and(|hint| total as i64 >= hint) {
+                break;
+            }
+        }
+        Python::with_gil(|py| {
+            Ok(
+                PyList::new_bound(py, lines.iter().map(|line| PyBytes::new_bound(py, line)))
+                    .into_py(py),
+            )
+        })
     }
 }
 
+/// Default `buffer_size` for [`ObjectOutputStream`]: writes are accumulated
+/// in memory up to this size before being flushed as a single multipart part,
+/// since most stores (S3, GCS) reject parts smaller than ~5 MiB.
+const DEFAULT_PART_SIZE: usize = 5 * 1024 * 1024;
+
+/// Default `single_shot_threshold` for [`ObjectOutputStream`]: outputs that
+/// close at or under this size are sent as one `put`, never touching
+/// `put_multipart` at all.
+const DEFAULT_SINGLE_SHOT_THRESHOLD: usize = 5 * 1024 * 1024;
+
 // TODO the C++ implementation track an internal lock on all random access files, DO we need this here?
-// TODO add buffer to store data ...
 #[pyclass(weakref)]
 pub struct ObjectOutputStream {
     pub store: Arc<DynObjectStore>,
     rt: Arc<Runtime>,
     pub path: Path,
-    writer: Box<dyn MultipartUpload>,
+    /// The multipart writer, created lazily by [`Self::ensure_writer`] once
+    /// `buffer` crosses `single_shot_threshold` -- absent until then, and
+    /// absent for good if `close()` is reached first, in which case the
+    /// whole output goes out as a single `put_opts` instead.
+    writer: Option<Box<dyn MultipartUpload>>,
+    /// Content-type/cache headers and tags/custom metadata requested via
+    /// `open_output_stream`'s `metadata` argument, sent with whichever of
+    /// `put_opts`/`put_multipart_opts` ends up finalizing the write.
+    put_opts: PutMultipartOpts,
+    /// Bytes accumulated since the last part was uploaded, or -- while still
+    /// below `single_shot_threshold` -- the entire output so far.
+    buffer: Vec<u8>,
+    part_size: usize,
+    single_shot_threshold: usize,
     pos: i64,
     #[pyo3(get)]
     closed: bool,
@@ -466,22 +662,26 @@ pub struct ObjectOutputStream {
 }
 
 impl ObjectOutputStream {
-    pub async fn try_new(
+    pub fn new(
         rt: Arc<Runtime>,
         store: Arc<DynObjectStore>,
         path: Path,
-    ) -> Result<Self, ObjectStoreError> {
-        match store.put_multipart(&path).await {
-            Ok(writer) => Ok(Self {
-                store,
-                rt,
-                path,
-                writer,
-                pos: 0,
-                closed: false,
-                mode: "wb".into(),
-            }),
-            Err(err) => Err(ObjectStoreError::ObjectStore(err)),
+        part_size: usize,
+        single_shot_threshold: usize,
+        put_opts: PutMultipartOpts,
+    ) -> Self {
+        Self {
+            store,
+            rt,
+            path,
+            writer: None,
+            put_opts,
+            buffer: Vec::with_capacity(usize::min(part_size, single_shot_threshold)),
+            part_size,
+            single_shot_threshold,
+            pos: 0,
+            closed: false,
+            mode: "wb".into(),
         }
     }
 
@@ -494,18 +694,88 @@ impl ObjectOutputStream {
 
         Ok(())
     }
+
+    /// Starts the multipart upload on first use -- once `buffer` has crossed
+    /// `single_shot_threshold` there's no longer any chance of finishing
+    /// with a single `put`, so the multipart writer is created and every
+    /// buffered byte up to this point will go out through it.
+    fn ensure_writer(&mut self) -> Result<(), InnerObjectStoreError> {
+        if self.writer.is_some() {
+            return Ok(());
+        }
+        let writer = self.rt.block_on(
+            self.store
+                .put_multipart_opts(&self.path, self.put_opts.clone()),
+        )?;
+        self.writer = Some(writer);
+        Ok(())
+    }
+
+    /// Uploads as many full-size parts out of `buffer` as possible, leaving
+    /// any sub-threshold remainder buffered -- most stores reject parts
+    /// smaller than their minimum part size. Only escalates to a multipart
+    /// writer once `buffer` crosses `single_shot_threshold`; below that it's
+    /// a no-op, leaving `close()` free to finish with a single `put`.
+    fn flush_full_parts(&mut self) -> Result<(), InnerObjectStoreError> {
+        if self.writer.is_none() && self.buffer.len() <= self.single_shot_threshold {
+            return Ok(());
+        }
+        self.ensure_writer()?;
+        let writer = self.writer.as_mut().unwrap();
+        while self.buffer.len() >= self.part_size {
+            let part: Vec<u8> = self.buffer.drain(..self.part_size).collect();
+            self.rt.block_on(writer.put_part(part.into()))?;
+        }
+        Ok(())
+    }
+
+    /// Aborts the in-progress multipart upload, if one was ever started.
+    fn abort(&mut self) -> Result<(), InnerObjectStoreError> {
+        match self.writer.as_mut() {
+            Some(writer) => self.rt.block_on(writer.abort()),
+            None => Ok(()),
+        }
+    }
 }
 
 #[pymethods]
 impl ObjectOutputStream {
     fn close(&mut self) -> PyResult<()> {
         self.closed = true;
-        match self.rt.block_on(self.writer.complete()) {
+
+        if let Err(err) = self.flush_full_parts() {
+            self.abort().map_err(ObjectStoreError::from)?;
+            return Err(ObjectStoreError::from(err).into());
+        }
+
+        let Some(writer) = self.writer.as_mut() else {
+            // Stayed at or under `single_shot_threshold` for the whole
+            // write -- finalize with a single PUT instead of initiating a
+            // multipart upload at all.
+            let payload = std::mem::take(&mut self.buffer);
+            let opts = PutOptions {
+                attributes: self.put_opts.attributes.clone(),
+                tags: self.put_opts.tags.clone(),
+                ..Default::default()
+            };
+            self.rt
+                .block_on(self.store.put_opts(&self.path, payload.into(), opts))
+                .map_err(ObjectStoreError::from)?;
+            return Ok(());
+        };
+
+        if !self.buffer.is_empty() {
+            let part = std::mem::take(&mut self.buffer);
+            if let Err(err) = self.rt.block_on(writer.put_part(part.into())) {
+                self.abort().map_err(ObjectStoreError::from)?;
+                return Err(ObjectStoreError::from(err).into());
+            }
+        }
+
+        match self.rt.block_on(self.writer.as_mut().unwrap().complete()) {
             Ok(_) => Ok(()),
             Err(err) => {
-                self.rt
-                    .block_on(self.writer.abort())
-                    .map_err(ObjectStoreError::from)?;
+                self.abort().map_err(ObjectStoreError::from)?;
                 Err(ObjectStoreError::from(err).into())
             }
         }
@@ -549,26 +819,28 @@ impl ObjectOutputStream {
 
     fn write(&mut self, data: Bound<'_, PyBytes>) -> PyResult<i64> {
         self.check_closed()?;
-        let bytes = data.as_bytes().to_vec();
+        let bytes = data.as_bytes();
         let len = bytes.len() as i64;
-        match self.rt.block_on(self.writer.put_part(bytes.into())) {
-            Ok(_) => Ok(len),
+        self.buffer.extend_from_slice(bytes);
+        match self.flush_full_parts() {
+            Ok(()) => Ok(len),
             Err(err) => {
-                self.rt
-                    .block_on(self.writer.abort())
-                    .map_err(ObjectStoreError::from)?;
+                self.abort().map_err(ObjectStoreError::from)?;
                 Err(ObjectStoreError::from(err).into())
             }
         }
     }
 
+    /// Drains any full-size parts out of the in-memory buffer, leaving a
+    /// sub-threshold tail buffered rather than completing the upload --
+    /// unlike `close()`, this never finalizes the write, and never forces
+    /// the single-shot path to escalate to multipart by itself.
     fn flush(&mut self) -> PyResult<()> {
-        match self.rt.block_on(self.writer.complete()) {
-            Ok(_) => Ok(()),
+        self.check_closed()?;
+        match self.flush_full_parts() {
+            Ok(()) => Ok(()),
             Err(err) => {
-                self.rt
-                    .block_on(self.writer.abort())
-                    .map_err(ObjectStoreError::from)?;
+                self.abort().map_err(ObjectStoreError::from)?;
                 Err(ObjectStoreError::from(err).into())
             }
         }
@@ -592,3 +864,85 @@ impl ObjectOutputStream {
         ))
     }
 }
+
+#[cfg(test)]
+mod output_stream_tests {
+    use super::*;
+    use crate::utils::get_bytes;
+    use object_store::memory::InMemory;
+
+    fn harness(
+        part_size: usize,
+        single_shot_threshold: usize,
+    ) -> (Arc<Runtime>, ObjectOutputStream) {
+        let rt = Arc::new(Runtime::new().unwrap());
+        let store: Arc<DynObjectStore> = Arc::new(InMemory::new());
+        let stream = ObjectOutputStream::new(
+            rt.clone(),
+            store,
+            Path::from("out.bin"),
+            part_size,
+            single_shot_threshold,
+            PutMultipartOpts::default(),
+        );
+        (rt, stream)
+    }
+
+    #[test]
+    fn close_below_single_shot_threshold_sends_a_single_put() {
+        let (rt, mut stream) = harness(1024, 1024);
+        let store = stream.store.clone();
+        let path = stream.path.clone();
+
+        stream.buffer.extend_from_slice(b"hello world");
+        stream.close().unwrap();
+
+        assert!(
+            stream.writer.is_none(),
+            "an output under single_shot_threshold should never start a multipart upload"
+        );
+        assert_eq!(
+            rt.block_on(get_bytes(store.as_ref(), &path)).unwrap(),
+            b"hello world".to_vec()
+        );
+    }
+
+    #[test]
+    fn crossing_single_shot_threshold_escalates_to_multipart_and_drains_full_parts() {
+        let part_size = 8;
+        let (rt, mut stream) = harness(part_size, 8);
+        let store = stream.store.clone();
+        let path = stream.path.clone();
+
+        let payload = b"abcdefghijklmnopqrstuvwxyz"; // 26 bytes > single_shot_threshold
+        stream.buffer.extend_from_slice(payload);
+        stream.flush_full_parts().unwrap();
+
+        assert!(
+            stream.writer.is_some(),
+            "buffer crossed single_shot_threshold, so a multipart writer should have started"
+        );
+        assert!(
+            stream.buffer.len() < part_size,
+            "flush_full_parts should drain every full-size part, leaving only a sub-part_size tail"
+        );
+
+        stream.close().unwrap();
+        assert_eq!(
+            rt.block_on(get_bytes(store.as_ref(), &path)).unwrap(),
+            payload.to_vec()
+        );
+    }
+
+    #[test]
+    fn flush_never_finalizes_the_upload() {
+        let (_rt, mut stream) = harness(1024, 1024);
+        stream.buffer.extend_from_slice(b"not yet closed");
+        stream.flush().unwrap();
+        assert!(!stream.closed, "flush must not mark the stream closed");
+        assert!(
+            stream.writer.is_none(),
+            "flush below single_shot_threshold must not start a multipart upload"
+        );
+    }
+}
@@ -1,27 +1,36 @@
 mod builder;
 mod file;
 mod utils;
+mod webhdfs;
 
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
 pub use crate::file::{ArrowFileSystemHandler, ObjectInputFile, ObjectOutputStream};
-use crate::utils::{flatten_list_stream, get_bytes};
+use crate::utils::{exists, flatten_list_stream, get_bytes, head_many};
 
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use futures::stream::{self, BoxStream, StreamExt};
 use object_store::path::{Error as PathError, Path};
 use object_store::{
-    BackoffConfig, ClientOptions, DynObjectStore, Error as InnerObjectStoreError, ListResult,
-    ObjectMeta, RetryConfig,
+    BackoffConfig, ClientOptions, DynObjectStore, Error as InnerObjectStoreError, GetOptions,
+    GetRange, ListResult, MultipartUpload, ObjectMeta, Result as InnerObjectStoreResult,
+    RetryConfig,
 };
 use pyo3::exceptions::{
     PyException, PyFileExistsError, PyFileNotFoundError, PyNotImplementedError,
+    PyStopAsyncIteration,
 };
 use pyo3::prelude::*;
+use pyo3::types::PyBytes;
 use pyo3::PyErr;
 use tokio::runtime::Runtime;
+use tokio::sync::Mutex as AsyncMutex;
 
 pub use builder::ObjectStoreBuilder;
 
@@ -80,6 +89,11 @@ impl From<PyErr> for ObjectStoreError {
     }
 }
 
+/// Raised by `get_opts`/`get_opts_async` when `if_none_match` or
+/// `if_modified_since` rules out a refetch, mirroring an HTTP 304: the
+/// caller's cached copy is still good and no bytes were transferred.
+pyo3::create_exception!(_internal, NotModified, PyException);
+
 impl From<ObjectStoreError> for PyErr {
     fn from(err: ObjectStoreError) -> PyErr {
         match err {
@@ -91,6 +105,9 @@ impl From<ObjectStoreError> for PyErr {
                 InnerObjectStoreError::AlreadyExists { .. } => {
                     PyFileExistsError::new_err(store_err.to_string())
                 }
+                InnerObjectStoreError::NotModified { .. } => {
+                    NotModified::new_err(store_err.to_string())
+                }
                 _ => PyException::new_err(store_err.to_string()),
             },
             _ => PyException::new_err(err.to_string()),
@@ -219,6 +236,652 @@ impl From<ListResult> for PyListResult {
     }
 }
 
+/// Default fan-out width for the `_many`/`_prefix` batch operations on
+/// [`PyObjectStore`].
+const DEFAULT_BATCH_CONCURRENCY: usize = 16;
+
+/// Delete `locations` via [`ObjectStore::delete_stream`], which batches
+/// onto the backend's native bulk-delete API when it has one (e.g. S3
+/// `DeleteObjects`) and falls back to concurrent per-key deletes otherwise.
+/// `delete_stream` has its own fixed internal fan-out, so `max_concurrency`
+/// is enforced here by chunking `locations` into `max_concurrency`-sized
+/// groups and running the groups one at a time, rather than handing the
+/// whole list to a single unbounded `delete_stream` call.
+async fn delete_many_inner(
+    inner: Arc<DynObjectStore>,
+    locations: Vec<Path>,
+    max_concurrency: usize,
+) -> Vec<(String, Option<String>)> {
+    let max_concurrency = max_concurrency.max(1);
+    let mut results = Vec::with_capacity(locations.len());
+    for chunk in locations.chunks(max_concurrency) {
+        let labels: Vec<String> = chunk.iter().map(Path::to_string).collect();
+        let input = stream::iter(chunk.iter().cloned().map(Ok).collect::<Vec<_>>()).boxed();
+        results.extend(
+            inner
+                .delete_stream(input)
+                .collect::<Vec<_>>()
+                .await
+                .into_iter()
+                .zip(labels)
+                .map(|(result, label)| (label, result.err().map(|err| err.to_string()))),
+        );
+    }
+    results
+}
+
+async fn delete_prefix_inner(
+    inner: Arc<DynObjectStore>,
+    prefix: Option<Path>,
+    max_concurrency: usize,
+) -> InnerObjectStoreResult<Vec<(String, Option<String>)>> {
+    let locations = flatten_list_stream(inner.as_ref(), prefix.as_ref())
+        .await?
+        .into_iter()
+        .map(|meta| meta.location)
+        .collect();
+    Ok(delete_many_inner(inner, locations, max_concurrency).await)
+}
+
+async fn copy_many_inner(
+    inner: Arc<DynObjectStore>,
+    pairs: Vec<(Path, Path)>,
+    max_concurrency: usize,
+) -> Vec<(String, Option<String>)> {
+    stream::iter(pairs)
+        .map(|(from, to)| {
+            let inner = inner.clone();
+            async move {
+                let label = format!("{} -> {}", from, to);
+                let result = inner.copy(&from, &to).await;
+                (label, result.err().map(|err| err.to_string()))
+            }
+        })
+        .buffer_unordered(max_concurrency.max(1))
+        .collect()
+        .await
+}
+
+/// Treat `prefix` as a logical "directory": its path joined with a
+/// trailing slash, so `a/b` and `a/b/` both produce `a/b/`. An empty/root
+/// prefix produces `""`.
+fn dir_prefix(prefix: &Path) -> String {
+    let s = prefix.as_ref();
+    if s.is_empty() || s.ends_with('/') {
+        s.to_string()
+    } else {
+        format!("{s}/")
+    }
+}
+
+/// Compute the `(from, to)` pairs that re-root every object under
+/// `from_prefix` to the same relative path under `to_prefix`.
+async fn rebase_prefix_pairs(
+    inner: &DynObjectStore,
+    from_prefix: Option<&Path>,
+    to_prefix: &Path,
+) -> InnerObjectStoreResult<Vec<(Path, Path)>> {
+    let from_dir = from_prefix.map(dir_prefix).unwrap_or_default();
+    let to_dir = dir_prefix(to_prefix);
+
+    let objects = flatten_list_stream(inner, from_prefix).await?;
+    Ok(objects
+        .into_iter()
+        .map(|meta| {
+            let relative = meta
+                .location
+                .as_ref()
+                .strip_prefix(&from_dir)
+                .unwrap_or_else(|| meta.location.as_ref());
+            let to = Path::from(format!("{to_dir}{relative}"));
+            (meta.location, to)
+        })
+        .collect())
+}
+
+/// List everything under `from_prefix`, re-root each object under
+/// `to_prefix` at the same relative path, and copy them all concurrently
+/// (bounded by `max_concurrency`).
+async fn copy_prefix_inner(
+    inner: Arc<DynObjectStore>,
+    from_prefix: Option<Path>,
+    to_prefix: Path,
+    max_concurrency: usize,
+) -> InnerObjectStoreResult<Vec<(String, Option<String>)>> {
+    let pairs = rebase_prefix_pairs(inner.as_ref(), from_prefix.as_ref(), &to_prefix).await?;
+    Ok(copy_many_inner(inner, pairs, max_concurrency).await)
+}
+
+/// Like [`copy_prefix_inner`], but deletes each source object once its copy
+/// succeeds. Objects whose copy failed are left in place; objects that were
+/// copied but whose source delete failed are reported with a distinct error
+/// so callers can tell the two failure modes apart.
+async fn rename_prefix_inner(
+    inner: Arc<DynObjectStore>,
+    from_prefix: Option<Path>,
+    to_prefix: Path,
+    max_concurrency: usize,
+) -> InnerObjectStoreResult<Vec<(String, Option<String>)>> {
+    let pairs = rebase_prefix_pairs(inner.as_ref(), from_prefix.as_ref(), &to_prefix).await?;
+
+    let mut results = copy_many_inner(inner.clone(), pairs, max_concurrency).await;
+
+    // `results` may be reordered relative to `pairs` since copies run via
+    // `buffer_unordered`, so recover each source from its own label rather
+    // than zipping positionally.
+    let to_delete: Vec<Path> = results
+        .iter()
+        .filter_map(|(label, err)| {
+            err.is_none()
+                .then(|| label.split(" -> ").next().map(Path::from))
+                .flatten()
+        })
+        .collect();
+    let delete_errors: HashMap<String, String> =
+        delete_many_inner(inner, to_delete, max_concurrency)
+            .await
+            .into_iter()
+            .filter_map(|(path, err)| err.map(|err| (path, err)))
+            .collect();
+
+    for (label, err) in results.iter_mut() {
+        if err.is_some() {
+            continue;
+        }
+        if let Some(from) = label.split(" -> ").next() {
+            if let Some(delete_err) = delete_errors.get(from) {
+                *err = Some(format!("copied but failed to delete source: {delete_err}"));
+            }
+        }
+    }
+    Ok(results)
+}
+
+/// Result of [`sync`]/`sync_async`: how many objects were actually copied,
+/// how many were left alone because the destination already matched, and
+/// which ones failed (with their error message).
+#[pyclass(name = "SyncSummary", subclass)]
+#[derive(Debug, Clone, Default)]
+pub struct PySyncSummary {
+    #[pyo3(get)]
+    copied: usize,
+    #[pyo3(get)]
+    skipped: usize,
+    #[pyo3(get)]
+    failed: Vec<(String, String)>,
+}
+
+/// Copy `meta` from `source` to `dest` at the same location, unless
+/// `overwrite` is `false` and `dest` already has an object there with a
+/// matching size. Returns whether a copy actually happened.
+async fn sync_one(
+    source: &DynObjectStore,
+    dest: &DynObjectStore,
+    meta: &ObjectMeta,
+    overwrite: bool,
+) -> InnerObjectStoreResult<bool> {
+    if !overwrite {
+        match dest.head(&meta.location).await {
+            Ok(existing) if existing.size == meta.size => return Ok(false),
+            Ok(_) => {}
+            Err(InnerObjectStoreError::NotFound { .. }) => {}
+            Err(err) => return Err(err),
+        }
+    }
+    let bytes = get_bytes(source, &meta.location).await?;
+    dest.put(&meta.location, bytes.into()).await?;
+    Ok(true)
+}
+
+async fn sync_inner(
+    source: Arc<DynObjectStore>,
+    dest: Arc<DynObjectStore>,
+    prefix: Option<Path>,
+    max_concurrency: usize,
+    overwrite: bool,
+) -> PyResult<PySyncSummary> {
+    let objects = flatten_list_stream(source.as_ref(), prefix.as_ref())
+        .await
+        .map_err(ObjectStoreError::from)?;
+
+    let results: Vec<(String, Result<bool, String>)> = stream::iter(objects)
+        .map(|meta| {
+            let source = source.clone();
+            let dest = dest.clone();
+            async move {
+                let location = meta.location.to_string();
+                let result = sync_one(source.as_ref(), dest.as_ref(), &meta, overwrite)
+                    .await
+                    .map_err(|err| err.to_string());
+                (location, result)
+            }
+        })
+        .buffer_unordered(max_concurrency.max(1))
+        .collect()
+        .await;
+
+    let mut summary = PySyncSummary::default();
+    for (location, result) in results {
+        match result {
+            Ok(true) => summary.copied += 1,
+            Ok(false) => summary.skipped += 1,
+            Err(err) => summary.failed.push((location, err)),
+        }
+    }
+    Ok(summary)
+}
+
+/// Mirror every object under `prefix` from `source` into `dest` at the same
+/// relative [`Path`], running transfers concurrently (bounded by
+/// `max_concurrency`). Unless `overwrite` is set, objects that already exist
+/// at the destination with a matching size are left alone and counted as
+/// `skipped` rather than `copied`.
+#[pyfunction]
+#[pyo3(text_signature = "(source, dest, prefix=None, max_concurrency=16, overwrite=False)")]
+#[pyo3(signature = (source, dest, prefix = None, max_concurrency = DEFAULT_BATCH_CONCURRENCY, overwrite = false))]
+pub fn sync(
+    source: &PyObjectStore,
+    dest: &PyObjectStore,
+    prefix: Option<PyPath>,
+    max_concurrency: usize,
+    overwrite: bool,
+) -> PyResult<PySyncSummary> {
+    source.rt.clone().block_on(sync_inner(
+        source.inner.clone(),
+        dest.inner.clone(),
+        prefix.map(Path::from),
+        max_concurrency,
+        overwrite,
+    ))
+}
+
+/// Mirror every object under `prefix` from `source` into `dest`. See
+/// [`sync`].
+#[pyfunction]
+#[pyo3(text_signature = "(source, dest, prefix=None, max_concurrency=16, overwrite=False)")]
+#[pyo3(signature = (source, dest, prefix = None, max_concurrency = DEFAULT_BATCH_CONCURRENCY, overwrite = false))]
+pub async fn sync_async(
+    source: &PyObjectStore,
+    dest: &PyObjectStore,
+    prefix: Option<PyPath>,
+    max_concurrency: usize,
+    overwrite: bool,
+) -> PyResult<PySyncSummary> {
+    let source = source.inner.clone();
+    let dest = dest.inner.clone();
+    let prefix = prefix.map(Path::from);
+    sync_inner(source, dest, prefix, max_concurrency, overwrite).await
+}
+
+/// Re-chunk a stream of arbitrarily-sized byte ranges into chunks of
+/// (at most) `chunk_size` bytes, buffering only the partial tail between
+/// calls rather than the whole object.
+fn rechunk_stream(
+    stream: BoxStream<'static, InnerObjectStoreResult<Bytes>>,
+    chunk_size: Option<usize>,
+) -> BoxStream<'static, InnerObjectStoreResult<Bytes>> {
+    let chunk_size = match chunk_size {
+        Some(chunk_size) if chunk_size > 0 => chunk_size,
+        _ => return stream,
+    };
+    futures::stream::unfold(
+        (stream, Vec::<u8>::new(), false),
+        move |(mut stream, mut buffer, stream_done)| async move {
+            while buffer.len() < chunk_size && !stream_done {
+                match stream.next().await {
+                    Some(Ok(bytes)) => buffer.extend_from_slice(&bytes),
+                    Some(Err(err)) => return Some((Err(err), (stream, Vec::new(), true))),
+                    None => break,
+                }
+            }
+            if buffer.is_empty() {
+                return None;
+            }
+            let take = chunk_size.min(buffer.len());
+            let rest = buffer.split_off(take);
+            let done = stream_done || rest.is_empty() && buffer.len() < chunk_size;
+            Some((Ok(Bytes::from(buffer)), (stream, rest, done)))
+        },
+    )
+    .boxed()
+}
+
+/// Turn a Python-friendly `(start, end)` pair into a [`GetRange::Bounded`].
+fn parse_get_range(range: Option<(usize, usize)>) -> PyResult<Option<GetRange>> {
+    match range {
+        None => Ok(None),
+        Some((start, end)) if end >= start => Ok(Some(GetRange::Bounded(start..end))),
+        Some((start, end)) => Err(ObjectStoreError::InputValue(format!(
+            "range end ({end}) must be >= range start ({start})"
+        ))
+        .into()),
+    }
+}
+
+/// Turn a Python-friendly Unix timestamp into the `DateTime<Utc>` that
+/// [`GetOptions`] expects.
+fn parse_since(timestamp: Option<i64>) -> PyResult<Option<DateTime<Utc>>> {
+    match timestamp {
+        None => Ok(None),
+        Some(ts) => DateTime::from_timestamp(ts, 0)
+            .map(Some)
+            .ok_or_else(|| ObjectStoreError::InputValue(format!("invalid timestamp: {ts}")).into()),
+    }
+}
+
+/// Assemble a [`GetOptions`] from the individual conditional-read
+/// parameters exposed on `get_opts`/`get_opts_async`.
+fn build_get_options(
+    range: Option<(usize, usize)>,
+    if_match: Option<String>,
+    if_none_match: Option<String>,
+    if_modified_since: Option<i64>,
+    if_unmodified_since: Option<i64>,
+) -> PyResult<GetOptions> {
+    Ok(GetOptions {
+        if_match,
+        if_none_match,
+        if_modified_since: parse_since(if_modified_since)?,
+        if_unmodified_since: parse_since(if_unmodified_since)?,
+        range: parse_get_range(range)?,
+        ..Default::default()
+    })
+}
+
+/// The result of [`PyObjectStore::get_stream`]/`get_stream_async`: the
+/// object's metadata paired with a lazy iterator over its bytes, so callers
+/// never have to materialize the whole payload in memory. The same object
+/// works as both a sync generator and an async iterator.
+#[pyclass(name = "GetResult", subclass)]
+pub struct PyGetResult {
+    #[pyo3(get)]
+    meta: PyObjectMeta,
+    rt: Arc<Runtime>,
+    stream: Arc<AsyncMutex<BoxStream<'static, InnerObjectStoreResult<Bytes>>>>,
+}
+
+impl PyGetResult {
+    fn new(
+        rt: Arc<Runtime>,
+        meta: PyObjectMeta,
+        stream: BoxStream<'static, InnerObjectStoreResult<Bytes>>,
+    ) -> Self {
+        Self {
+            meta,
+            rt,
+            stream: Arc::new(AsyncMutex::new(stream)),
+        }
+    }
+
+    fn next_chunk(&self) -> PyResult<Option<Bytes>> {
+        let stream = self.stream.clone();
+        self.rt
+            .block_on(async move { stream.lock().await.next().await })
+            .transpose()
+            .map_err(|err| ObjectStoreError::from(err).into())
+    }
+}
+
+#[pymethods]
+impl PyGetResult {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&self, py: Python<'_>) -> PyResult<Option<Py<PyBytes>>> {
+        let chunk = py.allow_threads(|| self.next_chunk())?;
+        Ok(chunk.map(|bytes| PyBytes::new(py, &bytes).into()))
+    }
+
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    async fn __anext__(&self) -> PyResult<Py<PyBytes>> {
+        let stream = self.stream.clone();
+        match stream.lock().await.next().await {
+            Some(Ok(bytes)) => Python::with_gil(|py| Ok(PyBytes::new(py, &bytes).into())),
+            Some(Err(err)) => Err(ObjectStoreError::from(err).into()),
+            None => Err(PyStopAsyncIteration::new_err(())),
+        }
+    }
+}
+
+/// Minimum part size honored by [`PyMultipartUpload`] before flushing a
+/// buffered part to the backend; below this most providers (S3, GCS) reject
+/// or refuse to finalize the part.
+const DEFAULT_MIN_PART_SIZE: usize = 5 * 1024 * 1024;
+
+async fn flush_full_parts(
+    writer: &AsyncMutex<Box<dyn MultipartUpload>>,
+    buffer: &AsyncMutex<Vec<u8>>,
+    min_part_size: usize,
+) -> InnerObjectStoreResult<()> {
+    let mut buffer = buffer.lock().await;
+    while buffer.len() >= min_part_size {
+        let part: Vec<u8> = buffer.drain(..min_part_size).collect();
+        writer.lock().await.put_part(part.into()).await?;
+    }
+    Ok(())
+}
+
+async fn flush_remainder(
+    writer: &AsyncMutex<Box<dyn MultipartUpload>>,
+    buffer: &AsyncMutex<Vec<u8>>,
+) -> InnerObjectStoreResult<()> {
+    let mut buffer = buffer.lock().await;
+    if !buffer.is_empty() {
+        let part = std::mem::take(&mut *buffer);
+        writer.lock().await.put_part(part.into()).await?;
+    }
+    Ok(())
+}
+
+/// Chunk size used by [`PyObjectStore::put_file`]/`get_to_file` (and their
+/// async forms) when streaming to/from a Python binary file-like object, so
+/// a multi-gigabyte object never has to be materialized in memory.
+const DEFAULT_FILE_STREAM_CHUNK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Read `fileobj` in `chunk_size` chunks and upload it to `location` via a
+/// native multipart writer, reading ahead up to `max_concurrency` chunks at
+/// a time and uploading that batch's parts concurrently (`buffer_unordered`)
+/// before reading the next batch, so peak memory stays bounded by
+/// `chunk_size * max_concurrency` rather than the whole object. Aborts the
+/// upload if `fileobj.read()` or any part upload fails.
+async fn put_file_inner(
+    inner: Arc<DynObjectStore>,
+    location: Path,
+    fileobj: Py<PyAny>,
+    chunk_size: usize,
+    max_concurrency: usize,
+) -> PyResult<()> {
+    let writer = inner
+        .put_multipart(&location)
+        .await
+        .map_err(ObjectStoreError::from)?;
+    let writer: Arc<AsyncMutex<Box<dyn MultipartUpload>>> = Arc::new(AsyncMutex::new(writer));
+
+    let upload = async {
+        loop {
+            let mut batch: Vec<Bytes> = Vec::with_capacity(max_concurrency);
+            for _ in 0..max_concurrency {
+                let chunk: Vec<u8> = Python::with_gil(|py| {
+                    fileobj
+                        .as_ref(py)
+                        .call_method1("read", (chunk_size,))?
+                        .extract()
+                })?;
+                if chunk.is_empty() {
+                    break;
+                }
+                batch.push(chunk.into());
+            }
+            if batch.is_empty() {
+                break;
+            }
+            let results: Vec<InnerObjectStoreResult<()>> = stream::iter(batch)
+                .map(|part| {
+                    let writer = writer.clone();
+                    async move {
+                        // Release the writer lock before awaiting the upload
+                        // so the batch's parts can be in flight together.
+                        let upload = writer.lock().await.put_part(part);
+                        upload.await
+                    }
+                })
+                .buffer_unordered(max_concurrency)
+                .collect()
+                .await;
+            for result in results {
+                result.map_err(ObjectStoreError::from)?;
+            }
+        }
+        Ok::<_, PyErr>(())
+    }
+    .await;
+
+    match upload {
+        Ok(()) => writer
+            .lock()
+            .await
+            .complete()
+            .await
+            .map_err(|err| ObjectStoreError::from(err).into()),
+        Err(err) => {
+            let _ = writer.lock().await.abort().await;
+            Err(err)
+        }
+    }
+}
+
+/// Stream the object at `location` in `chunk_size` chunks and write each one
+/// to `fileobj` in order, so a multi-gigabyte object never has to be
+/// materialized in memory. Returns the object's [`PyObjectMeta`].
+async fn get_to_file_inner(
+    inner: Arc<DynObjectStore>,
+    location: Path,
+    fileobj: Py<PyAny>,
+    chunk_size: usize,
+) -> PyResult<PyObjectMeta> {
+    let result = inner.get(&location).await.map_err(ObjectStoreError::from)?;
+    let meta = PyObjectMeta::from(result.meta.clone());
+    let mut stream = rechunk_stream(result.into_stream(), Some(chunk_size));
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(ObjectStoreError::from)?;
+        Python::with_gil(|py| -> PyResult<()> {
+            fileobj
+                .as_ref(py)
+                .call_method1("write", (PyBytes::new(py, &chunk),))?;
+            Ok(())
+        })?;
+    }
+    Ok(meta)
+}
+
+/// A streaming multipart upload handle obtained via
+/// [`PyObjectStore::put_multipart`]/`put_multipart_async`. Writes are
+/// buffered internally and only flushed to the backend once they reach
+/// [`DEFAULT_MIN_PART_SIZE`], so a series of small `write()` calls doesn't
+/// turn into a series of tiny (likely rejected) parts. On `abort()` the
+/// in-progress upload is cancelled so no orphaned parts are billed.
+#[pyclass(name = "MultipartUpload", subclass)]
+pub struct PyMultipartUpload {
+    rt: Arc<Runtime>,
+    writer: Arc<AsyncMutex<Box<dyn MultipartUpload>>>,
+    buffer: Arc<AsyncMutex<Vec<u8>>>,
+    closed: Arc<AtomicBool>,
+}
+
+impl PyMultipartUpload {
+    fn new(rt: Arc<Runtime>, writer: Box<dyn MultipartUpload>) -> Self {
+        Self {
+            rt,
+            writer: Arc::new(AsyncMutex::new(writer)),
+            buffer: Arc::new(AsyncMutex::new(Vec::new())),
+            closed: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    fn check_closed(&self) -> Result<(), ObjectStoreError> {
+        if self.closed.load(Ordering::SeqCst) {
+            return Err(ObjectStoreError::Common(
+                "Operation on a completed or aborted upload".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    async fn write_impl(&self, data: Vec<u8>) -> PyResult<usize> {
+        let len = data.len();
+        self.buffer.lock().await.extend(data);
+        flush_full_parts(&self.writer, &self.buffer, DEFAULT_MIN_PART_SIZE)
+            .await
+            .map_err(ObjectStoreError::from)?;
+        Ok(len)
+    }
+
+    async fn complete_impl(&self) -> PyResult<()> {
+        flush_remainder(&self.writer, &self.buffer)
+            .await
+            .map_err(ObjectStoreError::from)?;
+        self.writer
+            .lock()
+            .await
+            .complete()
+            .await
+            .map_err(ObjectStoreError::from)?;
+        Ok(())
+    }
+
+    async fn abort_impl(&self) -> PyResult<()> {
+        self.writer
+            .lock()
+            .await
+            .abort()
+            .await
+            .map_err(ObjectStoreError::from)?;
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl PyMultipartUpload {
+    /// Buffer `data`, flushing any full parts to the backend.
+    #[pyo3(text_signature = "($self, data)")]
+    fn write(&self, py: Python<'_>, data: Vec<u8>) -> PyResult<usize> {
+        self.check_closed()?;
+        py.allow_threads(|| self.rt.block_on(self.write_impl(data)))
+    }
+
+    /// Buffer `data`, flushing any full parts to the backend.
+    #[pyo3(text_signature = "($self, data)")]
+    async fn write_async(&self, data: Vec<u8>) -> PyResult<usize> {
+        self.check_closed()?;
+        self.write_impl(data).await
+    }
+
+    /// Flush any buffered bytes as the final part and finalize the upload.
+    fn complete(&self, py: Python<'_>) -> PyResult<()> {
+        self.check_closed()?;
+        self.closed.store(true, Ordering::SeqCst);
+        py.allow_threads(|| self.rt.block_on(self.complete_impl()))
+    }
+
+    /// Flush any buffered bytes as the final part and finalize the upload.
+    async fn complete_async(&self) -> PyResult<()> {
+        self.check_closed()?;
+        self.closed.store(true, Ordering::SeqCst);
+        self.complete_impl().await
+    }
+
+    /// Abort the upload, discarding any parts already stored so nothing is
+    /// billed for the incomplete object.
+    fn abort(&self, py: Python<'_>) -> PyResult<()> {
+        self.closed.store(true, Ordering::SeqCst);
+        py.allow_threads(|| self.rt.block_on(self.abort_impl()))
+    }
+}
+
 #[pyclass(name = "ClientOptions")]
 #[derive(Debug, Clone, Default)]
 pub struct PyClientOptions {
@@ -480,6 +1143,60 @@ pub struct PyObjectStore {
     options: Option<HashMap<String, String>>,
 }
 
+impl PyObjectStore {
+    /// Rebuild this store from `root_url` with `extra` merged into `options`,
+    /// so a decorator (throttling, concurrency limit, ...) is recorded as
+    /// plain `options` entries. That keeps `__getnewargs__` working for free:
+    /// pickling sends `(root_url, options)` and unpickling re-runs
+    /// [`PyObjectStore::new`], which reapplies every decorator through
+    /// [`ObjectStoreBuilder`].
+    fn rebuild_with_options(&self, extra: HashMap<String, String>) -> PyResult<Self> {
+        let mut options = self.options.clone().unwrap_or_default();
+        options.extend(extra);
+        Self::new(self.root_url.clone(), Some(options), None)
+    }
+
+    async fn head_impl(&self, location: PyPath) -> PyResult<PyObjectMeta> {
+        let meta = self
+            .inner
+            .head(&location.into())
+            .await
+            .map_err(ObjectStoreError::from)?;
+        Ok(meta.into())
+    }
+
+    async fn delete_impl(&self, location: PyPath) -> PyResult<()> {
+        self.inner
+            .delete(&location.into())
+            .await
+            .map_err(ObjectStoreError::from)?;
+        Ok(())
+    }
+
+    async fn copy_impl(&self, from: PyPath, to: PyPath) -> PyResult<()> {
+        self.inner
+            .copy(&from.into(), &to.into())
+            .await
+            .map_err(ObjectStoreError::from)?;
+        Ok(())
+    }
+
+    async fn rename_impl(&self, from: PyPath, to: PyPath) -> PyResult<()> {
+        self.inner
+            .rename(&from.into(), &to.into())
+            .await
+            .map_err(ObjectStoreError::from)?;
+        Ok(())
+    }
+
+    async fn exists_impl(&self, location: PyPath) -> PyResult<bool> {
+        exists(self.inner.as_ref(), &location.into())
+            .await
+            .map_err(ObjectStoreError::from)
+            .map_err(Into::into)
+    }
+}
+
 #[pymethods]
 impl PyObjectStore {
     #[new]
@@ -517,22 +1234,124 @@ impl PyObjectStore {
 
     /// Save the provided bytes to the specified location.
     #[pyo3(text_signature = "($self, location, bytes)")]
-    fn put_async<'a>(
-        &'a self,
-        py: Python<'a>,
+    async fn put_async(&self, location: PyPath, bytes: Vec<u8>) -> PyResult<()> {
+        let inner = self.inner.clone();
+        inner
+            .put(&location.into(), bytes.into())
+            .await
+            .map_err(ObjectStoreError::from)?;
+        Ok(())
+    }
+
+    /// Open a streaming multipart upload to `location`. Returns a
+    /// [`PyMultipartUpload`] handle with `write`/`write_async`,
+    /// `complete`/`complete_async`, and `abort` methods that drive the
+    /// backend's native multipart machinery (S3 multipart, Azure block
+    /// blobs, GCS resumable uploads).
+    #[pyo3(text_signature = "($self, location)")]
+    fn put_multipart(&self, location: PyPath) -> PyResult<PyMultipartUpload> {
+        let writer = self
+            .rt
+            .block_on(self.inner.put_multipart(&location.into()))
+            .map_err(ObjectStoreError::from)?;
+        Ok(PyMultipartUpload::new(self.rt.clone(), writer))
+    }
+
+    /// Open a streaming multipart upload to `location`. See
+    /// [`PyObjectStore::put_multipart`].
+    #[pyo3(text_signature = "($self, location)")]
+    async fn put_multipart_async(&self, location: PyPath) -> PyResult<PyMultipartUpload> {
+        let inner = self.inner.clone();
+        let rt = self.rt.clone();
+        let writer = inner
+            .put_multipart(&location.into())
+            .await
+            .map_err(ObjectStoreError::from)?;
+        Ok(PyMultipartUpload::new(rt, writer))
+    }
+
+    /// Stream `fileobj` (a binary file-like object opened for reading) to
+    /// `location` via a native multipart upload, `chunk_size` bytes at a
+    /// time, so the object never has to be fully loaded into memory. See
+    /// [`put_file_inner`].
+    #[pyo3(text_signature = "($self, location, fileobj, chunk_size=None, max_concurrency=16)")]
+    #[pyo3(signature = (location, fileobj, chunk_size = None, max_concurrency = DEFAULT_BATCH_CONCURRENCY))]
+    fn put_file(
+        &self,
+        py: Python<'_>,
         location: PyPath,
-        bytes: Vec<u8>,
-    ) -> PyResult<&PyAny> {
+        fileobj: Py<PyAny>,
+        chunk_size: Option<usize>,
+        max_concurrency: usize,
+    ) -> PyResult<()> {
+        let chunk_size = chunk_size.unwrap_or(DEFAULT_FILE_STREAM_CHUNK_SIZE);
         let inner = self.inner.clone();
-        pyo3_asyncio::tokio::future_into_py(py, async move {
-            inner
-                .put(&location.into(), bytes.into())
-                .await
-                .map_err(ObjectStoreError::from)?;
-            Ok(())
+        py.allow_threads(|| {
+            self.rt.block_on(put_file_inner(
+                inner,
+                location.into(),
+                fileobj,
+                chunk_size,
+                max_concurrency,
+            ))
         })
     }
 
+    /// Stream `fileobj` to `location`. See [`PyObjectStore::put_file`].
+    #[pyo3(text_signature = "($self, location, fileobj, chunk_size=None, max_concurrency=16)")]
+    #[pyo3(signature = (location, fileobj, chunk_size = None, max_concurrency = DEFAULT_BATCH_CONCURRENCY))]
+    async fn put_file_async(
+        &self,
+        location: PyPath,
+        fileobj: Py<PyAny>,
+        chunk_size: Option<usize>,
+        max_concurrency: usize,
+    ) -> PyResult<()> {
+        let chunk_size = chunk_size.unwrap_or(DEFAULT_FILE_STREAM_CHUNK_SIZE);
+        let inner = self.inner.clone();
+        put_file_inner(inner, location.into(), fileobj, chunk_size, max_concurrency).await
+    }
+
+    /// Stream the object at `location` to `fileobj` (a binary file-like
+    /// object opened for writing), `chunk_size` bytes at a time, so the
+    /// object never has to be fully loaded into memory. Returns the
+    /// object's [`PyObjectMeta`]. See [`get_to_file_inner`].
+    #[pyo3(text_signature = "($self, location, fileobj, chunk_size=None)")]
+    #[pyo3(signature = (location, fileobj, chunk_size = None))]
+    fn get_to_file(
+        &self,
+        py: Python<'_>,
+        location: PyPath,
+        fileobj: Py<PyAny>,
+        chunk_size: Option<usize>,
+    ) -> PyResult<PyObjectMeta> {
+        let chunk_size = chunk_size.unwrap_or(DEFAULT_FILE_STREAM_CHUNK_SIZE);
+        let inner = self.inner.clone();
+        py.allow_threads(|| {
+            self.rt.block_on(get_to_file_inner(
+                inner,
+                location.into(),
+                fileobj,
+                chunk_size,
+            ))
+        })
+    }
+
+    /// Stream the object at `location` to `fileobj`. See
+    /// [`PyObjectStore::get_to_file`].
+    #[pyo3(text_signature = "($self, location, fileobj, chunk_size=None)")]
+    #[pyo3(signature = (location, fileobj, chunk_size = None))]
+    async fn get_to_file_async(
+        &self,
+        location: PyPath,
+        fileobj: Py<PyAny>,
+        chunk_size: Option<usize>,
+    ) -> PyResult<PyObjectMeta> {
+        let chunk_size = chunk_size.unwrap_or(DEFAULT_FILE_STREAM_CHUNK_SIZE);
+        let inner = self.inner.clone();
+        get_to_file_inner(inner, location.into(), fileobj, chunk_size).await
+    }
+
     /// Return the bytes that are stored at the specified location.
     #[pyo3(text_signature = "($self, location)")]
     fn get(&self, location: PyPath) -> PyResult<Cow<[u8]>> {
@@ -545,14 +1364,50 @@ impl PyObjectStore {
 
     /// Return the bytes that are stored at the specified location.
     #[pyo3(text_signature = "($self, location)")]
-    fn get_async<'a>(&'a self, py: Python<'a>, location: PyPath) -> PyResult<&PyAny> {
+    async fn get_async(&self, location: PyPath) -> PyResult<Cow<[u8]>> {
         let inner = self.inner.clone();
-        pyo3_asyncio::tokio::future_into_py(py, async move {
-            let obj = get_bytes(inner.as_ref(), &location.into())
-                .await
-                .map_err(ObjectStoreError::from)?;
-            Ok(Cow::<[u8]>::Owned(obj.to_vec()))
-        })
+        let obj = get_bytes(inner.as_ref(), &location.into())
+            .await
+            .map_err(ObjectStoreError::from)?;
+        Ok(Cow::Owned(obj.to_vec()))
+    }
+
+    /// Stream the object at `location` in chunks of (up to) `chunk_size` bytes
+    /// instead of buffering the whole payload into memory. The returned
+    /// [`PyGetResult`] exposes `meta` so callers can read size/last_modified
+    /// without a second `head` round-trip.
+    #[pyo3(text_signature = "($self, location, chunk_size=None)")]
+    #[pyo3(signature = (location, chunk_size = None))]
+    fn get_stream(&self, location: PyPath, chunk_size: Option<usize>) -> PyResult<PyGetResult> {
+        let result = self
+            .rt
+            .block_on(self.inner.get(&location.into()))
+            .map_err(ObjectStoreError::from)?;
+        let meta = PyObjectMeta::from(result.meta.clone());
+        let stream = rechunk_stream(result.into_stream(), chunk_size);
+        Ok(PyGetResult::new(self.rt.clone(), meta, stream))
+    }
+
+    /// Stream the object at `location` in chunks of (up to) `chunk_size` bytes
+    /// instead of buffering the whole payload into memory. The returned
+    /// [`PyGetResult`] exposes `meta` so callers can read size/last_modified
+    /// without a second `head` round-trip.
+    #[pyo3(text_signature = "($self, location, chunk_size=None)")]
+    #[pyo3(signature = (location, chunk_size = None))]
+    async fn get_stream_async(
+        &self,
+        location: PyPath,
+        chunk_size: Option<usize>,
+    ) -> PyResult<PyGetResult> {
+        let inner = self.inner.clone();
+        let rt = self.rt.clone();
+        let result = inner
+            .get(&location.into())
+            .await
+            .map_err(ObjectStoreError::from)?;
+        let meta = PyObjectMeta::from(result.meta.clone());
+        let stream = rechunk_stream(result.into_stream(), chunk_size);
+        Ok(PyGetResult::new(rt, meta, stream))
     }
 
     /// Return the bytes that are stored at the specified location in the given byte range
@@ -571,71 +1426,245 @@ impl PyObjectStore {
 
     /// Return the bytes that are stored at the specified location in the given byte range
     #[pyo3(text_signature = "($self, location, start, length)")]
-    fn get_range_async<'a>(
-        &'a self,
-        py: Python<'a>,
+    async fn get_range_async(
+        &self,
         location: PyPath,
         start: usize,
         length: usize,
-    ) -> PyResult<&PyAny> {
+    ) -> PyResult<Cow<[u8]>> {
         let inner = self.inner.clone();
         let range = std::ops::Range {
             start,
             end: start + length,
         };
+        let obj = inner
+            .get_range(&location.into(), range)
+            .await
+            .map_err(ObjectStoreError::from)?;
+        Ok(Cow::Owned(obj.to_vec()))
+    }
 
-        pyo3_asyncio::tokio::future_into_py(py, async move {
-            let obj = inner
-                .get_range(&location.into(), range)
-                .await
-                .map_err(ObjectStoreError::from)?;
-            Ok(Cow::<[u8]>::Owned(obj.to_vec()))
-        })
+    /// Conditional and ranged read: return the bytes at `location` together
+    /// with the resolved [`PyObjectMeta`], applying `range` and the
+    /// `if_match`/`if_none_match`/`if_modified_since`/`if_unmodified_since`
+    /// preconditions via [`GetOptions`]. Raises [`NotModified`] when
+    /// `if_none_match`/`if_modified_since` rule out a refetch, so a caller
+    /// can keep using its cached copy without downloading the object.
+    #[pyo3(
+        text_signature = "($self, location, range=None, if_match=None, if_none_match=None, if_modified_since=None, if_unmodified_since=None)"
+    )]
+    #[pyo3(signature = (location, range=None, if_match=None, if_none_match=None, if_modified_since=None, if_unmodified_since=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn get_opts(
+        &self,
+        location: PyPath,
+        range: Option<(usize, usize)>,
+        if_match: Option<String>,
+        if_none_match: Option<String>,
+        if_modified_since: Option<i64>,
+        if_unmodified_since: Option<i64>,
+    ) -> PyResult<(Cow<[u8]>, PyObjectMeta)> {
+        let options = build_get_options(
+            range,
+            if_match,
+            if_none_match,
+            if_modified_since,
+            if_unmodified_since,
+        )?;
+        let (meta, bytes) = self
+            .rt
+            .block_on(async {
+                let result = self.inner.get_opts(&location.into(), options).await?;
+                let meta = result.meta.clone();
+                let bytes = result.bytes().await?;
+                Ok::<_, InnerObjectStoreError>((meta, bytes))
+            })
+            .map_err(ObjectStoreError::from)?;
+        Ok((Cow::Owned(bytes.to_vec()), PyObjectMeta::from(meta)))
+    }
+
+    /// Conditional and ranged read: return the bytes at `location` together
+    /// with the resolved [`PyObjectMeta`]. See [`PyObjectStore::get_opts`].
+    #[pyo3(
+        text_signature = "($self, location, range=None, if_match=None, if_none_match=None, if_modified_since=None, if_unmodified_since=None)"
+    )]
+    #[pyo3(signature = (location, range=None, if_match=None, if_none_match=None, if_modified_since=None, if_unmodified_since=None))]
+    #[allow(clippy::too_many_arguments)]
+    async fn get_opts_async(
+        &self,
+        location: PyPath,
+        range: Option<(usize, usize)>,
+        if_match: Option<String>,
+        if_none_match: Option<String>,
+        if_modified_since: Option<i64>,
+        if_unmodified_since: Option<i64>,
+    ) -> PyResult<(Cow<[u8]>, PyObjectMeta)> {
+        let options = build_get_options(
+            range,
+            if_match,
+            if_none_match,
+            if_modified_since,
+            if_unmodified_since,
+        )?;
+        let inner = self.inner.clone();
+        let result = inner
+            .get_opts(&location.into(), options)
+            .await
+            .map_err(ObjectStoreError::from)?;
+        let meta = PyObjectMeta::from(result.meta.clone());
+        let bytes = result.bytes().await.map_err(ObjectStoreError::from)?;
+        Ok((Cow::Owned(bytes.to_vec()), meta))
     }
 
     /// Return the metadata for the specified location
     #[pyo3(text_signature = "($self, location)")]
-    fn head(&self, location: PyPath) -> PyResult<PyObjectMeta> {
-        let meta = self
-            .rt
-            .block_on(self.inner.head(&location.into()))
-            .map_err(ObjectStoreError::from)?;
-        Ok(meta.into())
+    fn head(&self, py: Python<'_>, location: PyPath) -> PyResult<PyObjectMeta> {
+        py.allow_threads(|| self.rt.block_on(self.head_impl(location)))
     }
 
     /// Return the metadata for the specified location
     #[pyo3(text_signature = "($self, location)")]
-    fn head_async<'a>(&'a self, py: Python<'a>, location: PyPath) -> PyResult<&PyAny> {
+    async fn head_async(&self, location: PyPath) -> PyResult<PyObjectMeta> {
+        self.head_impl(location).await
+    }
+
+    /// Check whether an object exists at `location`, mapping a `NotFound`
+    /// error from `head` to `False` instead of raising.
+    #[pyo3(text_signature = "($self, location)")]
+    fn exists(&self, py: Python<'_>, location: PyPath) -> PyResult<bool> {
+        py.allow_threads(|| self.rt.block_on(self.exists_impl(location)))
+    }
+
+    /// Check whether an object exists at `location`. See
+    /// [`PyObjectStore::exists`].
+    #[pyo3(text_signature = "($self, location)")]
+    async fn exists_async(&self, location: PyPath) -> PyResult<bool> {
+        self.exists_impl(location).await
+    }
+
+    /// Head a batch of locations concurrently. Returns a
+    /// `(path, meta, error)` triple per location, in the same order as
+    /// `locations`, with exactly one of `meta`/`error` set so one missing
+    /// key doesn't abort the whole batch.
+    #[pyo3(text_signature = "($self, locations)")]
+    fn head_many(
+        &self,
+        py: Python<'_>,
+        locations: Vec<PyPath>,
+    ) -> PyResult<Vec<(String, Option<PyObjectMeta>, Option<String>)>> {
         let inner = self.inner.clone();
-        pyo3_asyncio::tokio::future_into_py(py, async move {
-            let meta = inner
-                .head(&location.into())
-                .await
-                .map_err(ObjectStoreError::from)?;
-            Ok(PyObjectMeta::from(meta))
-        })
+        let paths: Vec<Path> = locations.into_iter().map(Path::from).collect();
+        let labels: Vec<String> = paths.iter().map(Path::to_string).collect();
+        let results = py.allow_threads(|| self.rt.block_on(head_many(inner, paths)));
+        Ok(labels
+            .into_iter()
+            .zip(results)
+            .map(|(label, result)| match result {
+                Ok(meta) => (label, Some(PyObjectMeta::from(meta)), None),
+                Err(err) => (label, None, Some(err.to_string())),
+            })
+            .collect())
+    }
+
+    /// Head a batch of locations concurrently. See
+    /// [`PyObjectStore::head_many`].
+    #[pyo3(text_signature = "($self, locations)")]
+    async fn head_many_async(
+        &self,
+        locations: Vec<PyPath>,
+    ) -> PyResult<Vec<(String, Option<PyObjectMeta>, Option<String>)>> {
+        let inner = self.inner.clone();
+        let paths: Vec<Path> = locations.into_iter().map(Path::from).collect();
+        let labels: Vec<String> = paths.iter().map(Path::to_string).collect();
+        let results = head_many(inner, paths).await;
+        Ok(labels
+            .into_iter()
+            .zip(results)
+            .map(|(label, result)| match result {
+                Ok(meta) => (label, Some(PyObjectMeta::from(meta)), None),
+                Err(err) => (label, None, Some(err.to_string())),
+            })
+            .collect())
     }
 
     /// Delete the object at the specified location.
     #[pyo3(text_signature = "($self, location)")]
-    fn delete(&self, location: PyPath) -> PyResult<()> {
-        self.rt
-            .block_on(self.inner.delete(&location.into()))
-            .map_err(ObjectStoreError::from)?;
-        Ok(())
+    fn delete(&self, py: Python<'_>, location: PyPath) -> PyResult<()> {
+        py.allow_threads(|| self.rt.block_on(self.delete_impl(location)))
     }
 
     /// Delete the object at the specified location.
     #[pyo3(text_signature = "($self, location)")]
-    fn delete_async<'a>(&'a self, py: Python<'a>, location: PyPath) -> PyResult<&PyAny> {
+    async fn delete_async(&self, location: PyPath) -> PyResult<()> {
+        self.delete_impl(location).await
+    }
+
+    /// Delete many objects concurrently (bounded by `max_concurrency`).
+    /// Returns a `(path, error)` pair per location, `error` being `None` on
+    /// success, so one missing key doesn't abort the whole batch.
+    #[pyo3(text_signature = "($self, locations, max_concurrency=16)")]
+    #[pyo3(signature = (locations, max_concurrency = DEFAULT_BATCH_CONCURRENCY))]
+    fn delete_many(
+        &self,
+        locations: Vec<PyPath>,
+        max_concurrency: usize,
+    ) -> PyResult<Vec<(String, Option<String>)>> {
+        let locations = locations.into_iter().map(Path::from).collect();
+        Ok(self.rt.block_on(delete_many_inner(
+            self.inner.clone(),
+            locations,
+            max_concurrency,
+        )))
+    }
+
+    /// Delete many objects concurrently (bounded by `max_concurrency`). See
+    /// [`PyObjectStore::delete_many`].
+    #[pyo3(text_signature = "($self, locations, max_concurrency=16)")]
+    #[pyo3(signature = (locations, max_concurrency = DEFAULT_BATCH_CONCURRENCY))]
+    async fn delete_many_async(
+        &self,
+        locations: Vec<PyPath>,
+        max_concurrency: usize,
+    ) -> PyResult<Vec<(String, Option<String>)>> {
         let inner = self.inner.clone();
-        pyo3_asyncio::tokio::future_into_py(py, async move {
-            inner
-                .delete(&location.into())
-                .await
-                .map_err(ObjectStoreError::from)?;
-            Ok(())
-        })
+        let locations = locations.into_iter().map(Path::from).collect();
+        Ok(delete_many_inner(inner, locations, max_concurrency).await)
+    }
+
+    /// List everything under `prefix` and delete it concurrently (bounded by
+    /// `max_concurrency`). Returns a `(path, error)` pair per object deleted.
+    #[pyo3(text_signature = "($self, prefix=None, max_concurrency=16)")]
+    #[pyo3(signature = (prefix = None, max_concurrency = DEFAULT_BATCH_CONCURRENCY))]
+    fn delete_prefix(
+        &self,
+        prefix: Option<PyPath>,
+        max_concurrency: usize,
+    ) -> PyResult<Vec<(String, Option<String>)>> {
+        self.rt
+            .block_on(delete_prefix_inner(
+                self.inner.clone(),
+                prefix.map(Path::from),
+                max_concurrency,
+            ))
+            .map_err(ObjectStoreError::from)
+            .map_err(Into::into)
+    }
+
+    /// List everything under `prefix` and delete it concurrently (bounded by
+    /// `max_concurrency`). See [`PyObjectStore::delete_prefix`].
+    #[pyo3(text_signature = "($self, prefix=None, max_concurrency=16)")]
+    #[pyo3(signature = (prefix = None, max_concurrency = DEFAULT_BATCH_CONCURRENCY))]
+    async fn delete_prefix_async(
+        &self,
+        prefix: Option<PyPath>,
+        max_concurrency: usize,
+    ) -> PyResult<Vec<(String, Option<String>)>> {
+        let inner = self.inner.clone();
+        let prefix = prefix.map(Path::from);
+        delete_prefix_inner(inner, prefix, max_concurrency)
+            .await
+            .map_err(ObjectStoreError::from)
+            .map_err(Into::into)
     }
 
     /// List all the objects with the given prefix.
@@ -661,18 +1690,12 @@ impl PyObjectStore {
     /// Prefixes are evaluated on a path segment basis, i.e. `foo/bar/` is a prefix
     /// of `foo/bar/x` but not of `foo/bar_baz/x`.
     #[pyo3(text_signature = "($self, prefix)")]
-    fn list_async<'a>(&'a self, py: Python<'a>, prefix: Option<PyPath>) -> PyResult<&PyAny> {
+    async fn list_async(&self, prefix: Option<PyPath>) -> PyResult<Vec<PyObjectMeta>> {
         let inner = self.inner.clone();
-        pyo3_asyncio::tokio::future_into_py(py, async move {
-            let object_metas = flatten_list_stream(inner.as_ref(), prefix.map(Path::from).as_ref())
-                .await
-                .map_err(ObjectStoreError::from)?;
-            let py_object_metas = object_metas
-                .into_iter()
-                .map(PyObjectMeta::from)
-                .collect::<Vec<_>>();
-            Ok(py_object_metas)
-        })
+        let object_metas = flatten_list_stream(inner.as_ref(), prefix.map(Path::from).as_ref())
+            .await
+            .map_err(ObjectStoreError::from)?;
+        Ok(object_metas.into_iter().map(PyObjectMeta::from).collect())
     }
 
     /// List objects with the given prefix and an implementation specific
@@ -700,50 +1723,116 @@ impl PyObjectStore {
     /// Prefixes are evaluated on a path segment basis, i.e. `foo/bar/` is a prefix
     /// of `foo/bar/x` but not of `foo/bar_baz/x`.
     #[pyo3(text_signature = "($self, prefix)")]
-    fn list_with_delimiter_async<'a>(
-        &'a self,
-        py: Python<'a>,
-        prefix: Option<PyPath>,
-    ) -> PyResult<&PyAny> {
+    async fn list_with_delimiter_async(&self, prefix: Option<PyPath>) -> PyResult<PyListResult> {
         let inner = self.inner.clone();
-        pyo3_asyncio::tokio::future_into_py(py, async move {
-            let list_result = inner
-                .list_with_delimiter(prefix.map(Path::from).as_ref())
-                .await
-                .map_err(ObjectStoreError::from)?;
-            Ok(PyListResult::from(list_result))
-        })
+        let list_result = inner
+            .list_with_delimiter(prefix.map(Path::from).as_ref())
+            .await
+            .map_err(ObjectStoreError::from)?;
+        Ok(PyListResult::from(list_result))
     }
 
     /// Copy an object from one path to another in the same object store.
     ///
     /// If there exists an object at the destination, it will be overwritten.
     #[pyo3(text_signature = "($self, from, to)")]
-    fn copy(&self, from: PyPath, to: PyPath) -> PyResult<()> {
-        self.rt
-            .block_on(self.inner.copy(&from.into(), &to.into()))
-            .map_err(ObjectStoreError::from)?;
-        Ok(())
+    fn copy(&self, py: Python<'_>, from: PyPath, to: PyPath) -> PyResult<()> {
+        py.allow_threads(|| self.rt.block_on(self.copy_impl(from, to)))
     }
 
     /// Copy an object from one path to another in the same object store.
     ///
     /// If there exists an object at the destination, it will be overwritten.
     #[pyo3(text_signature = "($self, from, to)")]
-    fn copy_async<'a>(&'a self, py: Python<'a>, from: PyPath, to: PyPath) -> PyResult<&PyAny> {
+    async fn copy_async(&self, from: PyPath, to: PyPath) -> PyResult<()> {
+        self.copy_impl(from, to).await
+    }
+
+    /// Copy many `(from, to)` pairs concurrently (bounded by
+    /// `max_concurrency`). Returns a `("from -> to", error)` pair per copy,
+    /// `error` being `None` on success, so one failed copy doesn't abort the
+    /// whole batch.
+    #[pyo3(text_signature = "($self, pairs, max_concurrency=16)")]
+    #[pyo3(signature = (pairs, max_concurrency = DEFAULT_BATCH_CONCURRENCY))]
+    fn copy_many(
+        &self,
+        pairs: Vec<(PyPath, PyPath)>,
+        max_concurrency: usize,
+    ) -> PyResult<Vec<(String, Option<String>)>> {
+        let pairs = pairs
+            .into_iter()
+            .map(|(from, to)| (Path::from(from), Path::from(to)))
+            .collect();
+        Ok(self
+            .rt
+            .block_on(copy_many_inner(self.inner.clone(), pairs, max_concurrency)))
+    }
+
+    /// Copy many `(from, to)` pairs concurrently (bounded by
+    /// `max_concurrency`). See [`PyObjectStore::copy_many`].
+    #[pyo3(text_signature = "($self, pairs, max_concurrency=16)")]
+    #[pyo3(signature = (pairs, max_concurrency = DEFAULT_BATCH_CONCURRENCY))]
+    async fn copy_many_async(
+        &self,
+        pairs: Vec<(PyPath, PyPath)>,
+        max_concurrency: usize,
+    ) -> PyResult<Vec<(String, Option<String>)>> {
         let inner = self.inner.clone();
-        pyo3_asyncio::tokio::future_into_py(py, async move {
-            inner
-                .copy(&from.into(), &to.into())
-                .await
-                .map_err(ObjectStoreError::from)?;
-            Ok(())
-        })
+        let pairs = pairs
+            .into_iter()
+            .map(|(from, to)| (Path::from(from), Path::from(to)))
+            .collect();
+        Ok(copy_many_inner(inner, pairs, max_concurrency).await)
+    }
+
+    /// List everything under `from_prefix`, treating it as a logical
+    /// directory (`a/b` is joined the same as `a/b/`), and copy each object
+    /// to the same relative path under `to_prefix`, concurrently (bounded
+    /// by `max_concurrency`). Returns a `("from -> to", error)` pair per
+    /// object rather than aborting on the first failure.
+    #[pyo3(text_signature = "($self, from_prefix, to_prefix, max_concurrency=16)")]
+    #[pyo3(signature = (from_prefix, to_prefix, max_concurrency = DEFAULT_BATCH_CONCURRENCY))]
+    fn copy_prefix(
+        &self,
+        from_prefix: Option<PyPath>,
+        to_prefix: PyPath,
+        max_concurrency: usize,
+    ) -> PyResult<Vec<(String, Option<String>)>> {
+        self.rt
+            .block_on(copy_prefix_inner(
+                self.inner.clone(),
+                from_prefix.map(Path::from),
+                to_prefix.into(),
+                max_concurrency,
+            ))
+            .map_err(ObjectStoreError::from)
+            .map_err(Into::into)
+    }
+
+    /// List everything under `from_prefix` and copy it to `to_prefix`
+    /// concurrently (bounded by `max_concurrency`). See
+    /// [`PyObjectStore::copy_prefix`].
+    #[pyo3(text_signature = "($self, from_prefix, to_prefix, max_concurrency=16)")]
+    #[pyo3(signature = (from_prefix, to_prefix, max_concurrency = DEFAULT_BATCH_CONCURRENCY))]
+    async fn copy_prefix_async(
+        &self,
+        from_prefix: Option<PyPath>,
+        to_prefix: PyPath,
+        max_concurrency: usize,
+    ) -> PyResult<Vec<(String, Option<String>)>> {
+        let inner = self.inner.clone();
+        let from_prefix = from_prefix.map(Path::from);
+        let to_prefix = to_prefix.into();
+        copy_prefix_inner(inner, from_prefix, to_prefix, max_concurrency)
+            .await
+            .map_err(ObjectStoreError::from)
+            .map_err(Into::into)
     }
 
     /// Copy an object from one path to another, only if destination is empty.
     ///
-    /// Will return an error if the destination already has an object.
+    /// Will raise `FileExistsError` if the destination already has an object,
+    /// making this suitable as a lightweight lock for commit protocols.
     #[pyo3(text_signature = "($self, from, to)")]
     fn copy_if_not_exists(&self, from: PyPath, to: PyPath) -> PyResult<()> {
         self.rt
@@ -754,22 +1843,16 @@ impl PyObjectStore {
 
     /// Copy an object from one path to another, only if destination is empty.
     ///
-    /// Will return an error if the destination already has an object.
+    /// Will raise `FileExistsError` if the destination already has an object,
+    /// making this suitable as a lightweight lock for commit protocols.
     #[pyo3(text_signature = "($self, from, to)")]
-    fn copy_if_not_exists_async<'a>(
-        &'a self,
-        py: Python<'a>,
-        from: PyPath,
-        to: PyPath,
-    ) -> PyResult<&PyAny> {
+    async fn copy_if_not_exists_async(&self, from: PyPath, to: PyPath) -> PyResult<()> {
         let inner = self.inner.clone();
-        pyo3_asyncio::tokio::future_into_py(py, async move {
-            inner
-                .copy_if_not_exists(&from.into(), &to.into())
-                .await
-                .map_err(ObjectStoreError::from)?;
-            Ok(())
-        })
+        inner
+            .copy_if_not_exists(&from.into(), &to.into())
+            .await
+            .map_err(ObjectStoreError::from)?;
+        Ok(())
     }
 
     /// Move an object from one path to another in the same object store.
@@ -779,11 +1862,8 @@ impl PyObjectStore {
     ///
     /// If there exists an object at the destination, it will be overwritten.
     #[pyo3(text_signature = "($self, from, to)")]
-    fn rename(&self, from: PyPath, to: PyPath) -> PyResult<()> {
-        self.rt
-            .block_on(self.inner.rename(&from.into(), &to.into()))
-            .map_err(ObjectStoreError::from)?;
-        Ok(())
+    fn rename(&self, py: Python<'_>, from: PyPath, to: PyPath) -> PyResult<()> {
+        py.allow_threads(|| self.rt.block_on(self.rename_impl(from, to)))
     }
 
     /// Move an object from one path to another in the same object store.
@@ -793,20 +1873,14 @@ impl PyObjectStore {
     ///
     /// If there exists an object at the destination, it will be overwritten.
     #[pyo3(text_signature = "($self, from, to)")]
-    fn rename_async<'a>(&'a self, py: Python<'a>, from: PyPath, to: PyPath) -> PyResult<&PyAny> {
-        let inner = self.inner.clone();
-        pyo3_asyncio::tokio::future_into_py(py, async move {
-            inner
-                .rename(&from.into(), &to.into())
-                .await
-                .map_err(ObjectStoreError::from)?;
-            Ok(())
-        })
+    async fn rename_async(&self, from: PyPath, to: PyPath) -> PyResult<()> {
+        self.rename_impl(from, to).await
     }
 
     /// Move an object from one path to another in the same object store.
     ///
-    /// Will return an error if the destination already has an object.
+    /// Will raise `FileExistsError` if the destination already has an object,
+    /// making this suitable as a lightweight lock for commit protocols.
     #[pyo3(text_signature = "($self, from, to)")]
     fn rename_if_not_exists(&self, from: PyPath, to: PyPath) -> PyResult<()> {
         self.rt
@@ -817,25 +1891,213 @@ impl PyObjectStore {
 
     /// Move an object from one path to another in the same object store.
     ///
-    /// Will return an error if the destination already has an object.
+    /// Will raise `FileExistsError` if the destination already has an object,
+    /// making this suitable as a lightweight lock for commit protocols.
     #[pyo3(text_signature = "($self, from, to)")]
-    fn rename_if_not_exists_async<'a>(
-        &'a self,
-        py: Python<'a>,
-        from: PyPath,
-        to: PyPath,
-    ) -> PyResult<&PyAny> {
+    async fn rename_if_not_exists_async(&self, from: PyPath, to: PyPath) -> PyResult<()> {
         let inner = self.inner.clone();
-        pyo3_asyncio::tokio::future_into_py(py, async move {
-            inner
-                .rename_if_not_exists(&from.into(), &to.into())
-                .await
-                .map_err(ObjectStoreError::from)?;
-            Ok(())
-        })
+        inner
+            .rename_if_not_exists(&from.into(), &to.into())
+            .await
+            .map_err(ObjectStoreError::from)?;
+        Ok(())
+    }
+
+    /// List everything under `from_prefix`, treating it as a logical
+    /// directory (`a/b` is joined the same as `a/b/`), and move each object
+    /// to the same relative path under `to_prefix`, concurrently (bounded
+    /// by `max_concurrency`). Sources are only deleted once their copy
+    /// succeeds; a failed copy leaves the source untouched, and a failed
+    /// source delete after a successful copy is reported distinctly.
+    #[pyo3(text_signature = "($self, from_prefix, to_prefix, max_concurrency=16)")]
+    #[pyo3(signature = (from_prefix, to_prefix, max_concurrency = DEFAULT_BATCH_CONCURRENCY))]
+    fn rename_prefix(
+        &self,
+        from_prefix: Option<PyPath>,
+        to_prefix: PyPath,
+        max_concurrency: usize,
+    ) -> PyResult<Vec<(String, Option<String>)>> {
+        self.rt
+            .block_on(rename_prefix_inner(
+                self.inner.clone(),
+                from_prefix.map(Path::from),
+                to_prefix.into(),
+                max_concurrency,
+            ))
+            .map_err(ObjectStoreError::from)
+            .map_err(Into::into)
+    }
+
+    /// List everything under `from_prefix` and move it to `to_prefix`
+    /// concurrently (bounded by `max_concurrency`). See
+    /// [`PyObjectStore::rename_prefix`].
+    #[pyo3(text_signature = "($self, from_prefix, to_prefix, max_concurrency=16)")]
+    #[pyo3(signature = (from_prefix, to_prefix, max_concurrency = DEFAULT_BATCH_CONCURRENCY))]
+    async fn rename_prefix_async(
+        &self,
+        from_prefix: Option<PyPath>,
+        to_prefix: PyPath,
+        max_concurrency: usize,
+    ) -> PyResult<Vec<(String, Option<String>)>> {
+        let inner = self.inner.clone();
+        let from_prefix = from_prefix.map(Path::from);
+        let to_prefix = to_prefix.into();
+        rename_prefix_inner(inner, from_prefix, to_prefix, max_concurrency)
+            .await
+            .map_err(ObjectStoreError::from)
+            .map_err(Into::into)
+    }
+
+    /// Return a copy of this store wrapped in a `ThrottledStore`, injecting
+    /// artificial latency into `get`/`list`/`put` calls. Useful for
+    /// reproducing slow-cloud behavior in tests and for being polite against
+    /// a rate-limited bucket. Unset arguments keep whatever wait this store's
+    /// `options` already carry for that operation (none, unless an earlier
+    /// `throttled` call set one). The config is recorded as `wait_*` `options` entries (see
+    /// [`PyObjectStore::rebuild_with_options`]), so the decorated store
+    /// survives pickling.
+    #[pyo3(
+        text_signature = "($self, wait_get_per_call=None, wait_list_per_call=None, wait_put_per_call=None)"
+    )]
+    #[pyo3(signature = (wait_get_per_call=None, wait_list_per_call=None, wait_put_per_call=None))]
+    fn throttled(
+        &self,
+        wait_get_per_call: Option<u64>,
+        wait_list_per_call: Option<u64>,
+        wait_put_per_call: Option<u64>,
+    ) -> PyResult<Self> {
+        let mut extra = HashMap::new();
+        if let Some(ms) = wait_get_per_call {
+            extra.insert("wait_get_per_call".to_string(), ms.to_string());
+        }
+        if let Some(ms) = wait_list_per_call {
+            extra.insert("wait_list_per_call".to_string(), ms.to_string());
+        }
+        if let Some(ms) = wait_put_per_call {
+            extra.insert("wait_put_per_call".to_string(), ms.to_string());
+        }
+        self.rebuild_with_options(extra)
+    }
+
+    /// Return a copy of this store wrapped in a `LimitStore`, bounding the
+    /// number of requests in flight at once to `max` via a shared semaphore.
+    /// The limit is recorded as a `max_concurrent_requests` `options` entry
+    /// (see [`PyObjectStore::rebuild_with_options`]), so the decorated store
+    /// survives pickling.
+    #[pyo3(text_signature = "($self, max)")]
+    fn with_concurrency_limit(&self, max: usize) -> PyResult<Self> {
+        self.rebuild_with_options(HashMap::from([(
+            "max_concurrent_requests".to_string(),
+            max.to_string(),
+        )]))
     }
 
     pub fn __getnewargs__(&self) -> PyResult<(String, Option<HashMap<String, String>>)> {
         Ok((self.root_url.clone(), self.options.clone()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use object_store::memory::InMemory;
+
+    fn in_memory() -> Arc<DynObjectStore> {
+        Arc::new(InMemory::new())
+    }
+
+    #[tokio::test]
+    async fn sync_skips_objects_whose_size_already_matches() {
+        let source = in_memory();
+        let dest = in_memory();
+        let path = Path::from("a.txt");
+        source
+            .put(&path, Bytes::from_static(b"same size").into())
+            .await
+            .unwrap();
+        // Same length as the source object but different bytes -- sync_one
+        // only compares size, so this must be left alone.
+        dest.put(&path, Bytes::from_static(b"same siz3").into())
+            .await
+            .unwrap();
+
+        let summary = sync_inner(source.clone(), dest.clone(), None, 4, false)
+            .await
+            .unwrap();
+
+        assert_eq!(summary.copied, 0);
+        assert_eq!(summary.skipped, 1);
+        assert_eq!(
+            get_bytes(dest.as_ref(), &path).await.unwrap(),
+            b"same siz3".to_vec()
+        );
+    }
+
+    #[tokio::test]
+    async fn sync_copies_missing_and_size_mismatched_objects() {
+        let source = in_memory();
+        let dest = in_memory();
+        let matching = Path::from("match.txt");
+        let missing = Path::from("missing.txt");
+        let resized = Path::from("resized.txt");
+        source
+            .put(&matching, Bytes::from_static(b"1234").into())
+            .await
+            .unwrap();
+        dest.put(&matching, Bytes::from_static(b"1234").into())
+            .await
+            .unwrap();
+        source
+            .put(&missing, Bytes::from_static(b"new").into())
+            .await
+            .unwrap();
+        source
+            .put(&resized, Bytes::from_static(b"longer-now").into())
+            .await
+            .unwrap();
+        dest.put(&resized, Bytes::from_static(b"short").into())
+            .await
+            .unwrap();
+
+        let summary = sync_inner(source.clone(), dest.clone(), None, 4, false)
+            .await
+            .unwrap();
+
+        assert_eq!(summary.copied, 2);
+        assert_eq!(summary.skipped, 1);
+        assert!(summary.failed.is_empty());
+        assert_eq!(
+            get_bytes(dest.as_ref(), &missing).await.unwrap(),
+            b"new".to_vec()
+        );
+        assert_eq!(
+            get_bytes(dest.as_ref(), &resized).await.unwrap(),
+            b"longer-now".to_vec()
+        );
+    }
+
+    #[tokio::test]
+    async fn sync_overwrite_forces_a_copy_even_when_size_matches() {
+        let source = in_memory();
+        let dest = in_memory();
+        let path = Path::from("a.txt");
+        source
+            .put(&path, Bytes::from_static(b"from-source").into())
+            .await
+            .unwrap();
+        dest.put(&path, Bytes::from_static(b"dest-value!").into())
+            .await
+            .unwrap();
+
+        let summary = sync_inner(source.clone(), dest.clone(), None, 4, true)
+            .await
+            .unwrap();
+
+        assert_eq!(summary.copied, 1);
+        assert_eq!(summary.skipped, 0);
+        assert_eq!(
+            get_bytes(dest.as_ref(), &path).await.unwrap(),
+            b"from-source".to_vec()
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::Arc;
+
+use futures::future::join_all;
+use futures::stream::{self, BoxStream};
+use futures::{StreamExt, TryStreamExt};
+use object_store::path::Path;
+use object_store::{
+    DynObjectStore, Error as ObjectStoreError, ListResult, ObjectMeta, Result as ObjectStoreResult,
+};
+use pyo3::prelude::*;
+use tokio::runtime::Runtime;
+
+/// Default cap on the number of `list_with_delimiter` calls [`walk_tree`] and
+/// [`walk_tree_stream`] keep in flight at once, so a deep, wide tree doesn't
+/// fan out to thousands of simultaneous requests.
+const DEFAULT_LIST_CONCURRENCY: usize = 16;
+
+/// Utility to collect rust futures with the GIL released, driven by a
+/// shared, long-lived runtime rather than spinning up a fresh one per call.
+pub fn wait_for_future<F: Future>(py: Python, rt: &Runtime, f: F) -> F::Output
+where
+    F: Send,
+    F::Output: Send,
+{
+    py.allow_threads(|| rt.block_on(f))
+}
+
+/// List directory
+pub async fn flatten_list_stream(
+    storage: &DynObjectStore,
+    prefix: Option<&Path>,
+) -> ObjectStoreResult<Vec<ObjectMeta>> {
+    storage
+        .list(prefix)
+        .await?
+        .try_collect::<Vec<ObjectMeta>>()
+        .await
+}
+
+/// Recursively lists everything under `path`, fanning out `list_with_delimiter`
+/// calls across the tree with at most [`DEFAULT_LIST_CONCURRENCY`] in flight
+/// at once rather than spawning one task per prefix per level.
+pub async fn walk_tree(
+    storage: Arc<DynObjectStore>,
+    path: &Path,
+    recursive: bool,
+) -> ObjectStoreResult<ListResult> {
+    let mut results = ListResult {
+        common_prefixes: vec![],
+        objects: vec![],
+    };
+    let mut frontier = VecDeque::from([path.clone()]);
+
+    while !frontier.is_empty() {
+        let pages = stream::iter(frontier.drain(..).collect::<Vec<_>>())
+            .map(|prefix| {
+                let storage = storage.clone();
+                async move { storage.list_with_delimiter(Some(&prefix)).await }
+            })
+            .buffer_unordered(DEFAULT_LIST_CONCURRENCY)
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        for page in pages {
+            if recursive {
+                frontier.extend(page.common_prefixes.clone());
+            }
+            results.common_prefixes.extend(page.common_prefixes);
+            results.objects.extend(page.objects);
+        }
+    }
+
+    Ok(results)
+}
+
+/// Like [`walk_tree`], but streams each level's objects as soon as it's
+/// listed instead of buffering the whole tree in memory, so a caller can
+/// start processing before the full recursive listing completes.
+pub fn walk_tree_stream(
+    storage: Arc<DynObjectStore>,
+    path: &Path,
+    recursive: bool,
+) -> BoxStream<'static, ObjectStoreResult<ObjectMeta>> {
+    let frontier = VecDeque::from([path.clone()]);
+    let ready = VecDeque::new();
+    stream::unfold(
+        (storage, frontier, ready),
+        move |(storage, mut frontier, mut ready)| async move {
+            loop {
+                if let Some(meta) = ready.pop_front() {
+                    return Some((Ok(meta), (storage, frontier, ready)));
+                }
+                if frontier.is_empty() {
+                    return None;
+                }
+
+                let pages = stream::iter(frontier.drain(..).collect::<Vec<_>>())
+                    .map(|prefix| {
+                        let storage = storage.clone();
+                        async move { storage.list_with_delimiter(Some(&prefix)).await }
+                    })
+                    .buffer_unordered(DEFAULT_LIST_CONCURRENCY)
+                    .collect::<Vec<_>>()
+                    .await;
+
+                for page in pages {
+                    match page {
+                        Ok(page) => {
+                            if recursive {
+                                frontier.extend(page.common_prefixes);
+                            }
+                            ready.extend(page.objects);
+                        }
+                        Err(err) => return Some((Err(err), (storage, frontier, ready))),
+                    }
+                }
+            }
+        },
+    )
+    .boxed()
+}
+
+/// Delete `locations` via [`ObjectStore::delete_stream`], which batches onto
+/// the backend's native bulk-delete API when it has one (e.g. S3
+/// `DeleteObjects`, up to 1000 keys per request) and falls back to
+/// concurrent per-key deletes otherwise. Returns the outcome of each
+/// deletion, in the same order as `locations`, so callers can report
+/// per-object failures instead of aborting on the first one.
+pub async fn bulk_delete(
+    storage: &DynObjectStore,
+    locations: Vec<Path>,
+) -> Vec<(Path, Option<String>)> {
+    let paths = locations.clone();
+    let input = stream::iter(locations.into_iter().map(Ok).collect::<Vec<_>>()).boxed();
+    storage
+        .delete_stream(input)
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .zip(paths)
+        .map(|(result, path)| (path, result.err().map(|err| err.to_string())))
+        .collect()
+}
+
+/// Delete everything under `prefix`. See [`bulk_delete`].
+pub async fn delete_dir(storage: &DynObjectStore, prefix: &Path) -> ObjectStoreResult<()> {
+    let locations = storage
+        .list(Some(prefix))
+        .map_ok(|meta| meta.location)
+        .try_collect::<Vec<_>>()
+        .await?;
+    let input = stream::iter(locations.into_iter().map(Ok).collect::<Vec<_>>()).boxed();
+    storage.delete_stream(input).try_collect::<Vec<_>>().await?;
+    Ok(())
+}
+
+/// get bytes from a location
+pub async fn get_bytes(storage: &DynObjectStore, path: &Path) -> ObjectStoreResult<Vec<u8>> {
+    Ok(storage.get(path).await?.bytes().await?.into())
+}
+
+/// Check whether an object exists at `path`, mapping `NotFound` to `false`.
+pub async fn exists(storage: &DynObjectStore, path: &Path) -> ObjectStoreResult<bool> {
+    match storage.head(path).await {
+        Ok(_) => Ok(true),
+        Err(ObjectStoreError::NotFound { .. }) => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+/// Head a batch of paths concurrently, returning the metadata (or error) for
+/// each in the same order as `paths`.
+pub async fn head_many(
+    storage: Arc<DynObjectStore>,
+    paths: Vec<Path>,
+) -> Vec<ObjectStoreResult<ObjectMeta>> {
+    let tasks = paths.into_iter().map(|path| {
+        let storage = storage.clone();
+        async move { storage.head(&path).await }
+    });
+    join_all(tasks).await
+}
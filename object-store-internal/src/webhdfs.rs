@@ -0,0 +1,499 @@
+//! A native [`ObjectStore`] implementation that speaks the WebHDFS REST
+//! protocol directly, so `webhdfs://namenode:9870/path` urls work against a
+//! Hadoop cluster without requiring a local HDFS client library.
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use futures::stream::{self, BoxStream, StreamExt};
+use object_store::path::Path;
+use object_store::{
+    Error as ObjectStoreError, GetOptions, GetResult, GetResultPayload, ListResult, MultipartId,
+    ObjectMeta, ObjectStore, Result as ObjectStoreResult,
+};
+use reqwest::{Client, Method, StatusCode, Url};
+use serde::Deserialize;
+use tokio::io::AsyncWrite;
+use tokio::sync::{mpsc, oneshot};
+
+fn generic(source: impl std::error::Error + Send + Sync + 'static) -> ObjectStoreError {
+    ObjectStoreError::Generic {
+        store: "WebHDFS",
+        source: Box::new(source),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct FileStatus {
+    #[serde(rename = "pathSuffix")]
+    path_suffix: String,
+    length: usize,
+    #[serde(rename = "modificationTime")]
+    modification_time: i64,
+    #[serde(rename = "type")]
+    file_type: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct FileStatuses {
+    #[serde(rename = "FileStatus")]
+    file_status: Vec<FileStatus>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListStatusResponse {
+    #[serde(rename = "FileStatuses")]
+    file_statuses: FileStatuses,
+}
+
+#[derive(Debug, Deserialize)]
+struct GetFileStatusResponse {
+    #[serde(rename = "FileStatus")]
+    file_status: FileStatus,
+}
+
+/// Configuration for talking to a WebHDFS namenode: `user.name`/`doas`
+/// query parameters and an `X-Hadoop-Delegation-Token` header sent with
+/// every request, plus a host remapping table for clusters whose redirect
+/// responses advertise internal datanode hostnames that aren't reachable
+/// from outside the cluster.
+#[derive(Debug, Clone, Default)]
+pub struct WebHdfsConfig {
+    pub user_name: Option<String>,
+    pub doas: Option<String>,
+    pub delegation_token: Option<String>,
+    pub host_remap: HashMap<String, String>,
+}
+
+/// An [`ObjectStore`] backed by a WebHDFS-compatible namenode, reached over
+/// plain HTTP(S) rather than the native RPC protocol.
+#[derive(Debug, Clone)]
+pub struct WebHdfs {
+    client: Client,
+    namenode: Url,
+    config: Arc<WebHdfsConfig>,
+}
+
+impl std::fmt::Display for WebHdfs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "WebHDFS({})", self.namenode)
+    }
+}
+
+impl WebHdfs {
+    pub fn new(namenode: Url, config: WebHdfsConfig) -> Self {
+        Self {
+            // Redirects are followed manually (see `redirected_write`/
+            // `get_range`) so `remap_redirect` gets a chance to rewrite an
+            // internal datanode hostname before the follow-up request goes
+            // out -- reqwest's default auto-follow would otherwise race
+            // ahead and try to connect to the unreachable internal host.
+            client: Client::builder()
+                .redirect(reqwest::redirect::Policy::none())
+                .build()
+                .expect("building the WebHDFS HTTP client"),
+            namenode,
+            config: Arc::new(config),
+        }
+    }
+
+    fn webhdfs_path(&self, location: &Path) -> String {
+        format!("/webhdfs/v1/{}", location.as_ref())
+    }
+
+    /// Build the request url for `op` against `location`, with the
+    /// configured identity query parameters attached.
+    fn request_url(&self, location: &Path, op: &str, extra: &[(&str, String)]) -> Url {
+        let mut url = self.namenode.clone();
+        url.set_path(&self.webhdfs_path(location));
+        {
+            let mut pairs = url.query_pairs_mut();
+            pairs.append_pair("op", op);
+            if let Some(user_name) = &self.config.user_name {
+                pairs.append_pair("user.name", user_name);
+            }
+            if let Some(doas) = &self.config.doas {
+                pairs.append_pair("doas", doas);
+            }
+            for (key, value) in extra {
+                pairs.append_pair(key, value);
+            }
+        }
+        url
+    }
+
+    /// Apply the delegation-token header, if configured, to a request.
+    fn authenticate(&self, builder: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match &self.config.delegation_token {
+            Some(token) => builder.header("X-Hadoop-Delegation-Token", token),
+            None => builder,
+        }
+    }
+
+    /// Remap the host of a namenode-issued redirect `Location` so that it
+    /// resolves to a reachable datanode, using [`WebHdfsConfig::host_remap`].
+    fn remap_redirect(&self, mut location: Url) -> Url {
+        if let Some(host) = location.host_str() {
+            if let Some(mapped) = self.config.host_remap.get(host) {
+                let _ = location.set_host(Some(mapped));
+            }
+        }
+        location
+    }
+
+    /// `CREATE`/`APPEND` both follow the same two-step dance: the namenode
+    /// responds with a `307` redirect to the datanode that should actually
+    /// receive the data, with no body of its own.
+    async fn redirected_write(
+        &self,
+        location: &Path,
+        op: &str,
+        extra: &[(&str, String)],
+        body: Bytes,
+    ) -> ObjectStoreResult<()> {
+        let url = self.request_url(location, op, extra);
+        let probe = self
+            .authenticate(self.client.request(Method::PUT, url))
+            .send()
+            .await
+            .map_err(generic)?;
+        let redirect = probe
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|v| v.to_str().ok())
+            .ok_or_else(|| {
+                generic(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    "WebHDFS namenode did not return a datanode redirect",
+                ))
+            })?;
+        let datanode_url = self.remap_redirect(Url::parse(redirect).map_err(generic)?);
+        let response = self
+            .authenticate(self.client.request(Method::PUT, datanode_url))
+            .body(body)
+            .send()
+            .await
+            .map_err(generic)?;
+        check_status(response, location).await?;
+        Ok(())
+    }
+
+    async fn list_status(&self, location: &Path) -> ObjectStoreResult<Vec<FileStatus>> {
+        let url = self.request_url(location, "LISTSTATUS", &[]);
+        let response = self
+            .authenticate(self.client.get(url))
+            .send()
+            .await
+            .map_err(generic)?;
+        let response = check_status(response, location).await?;
+        let parsed: ListStatusResponse = response.json().await.map_err(generic)?;
+        Ok(parsed.file_statuses.file_status)
+    }
+
+    fn child_meta(&self, parent: &Path, status: &FileStatus) -> ObjectMeta {
+        ObjectMeta {
+            location: parent.child(status.path_suffix.as_str()),
+            last_modified: chrono::Utc
+                .timestamp_millis_opt(status.modification_time)
+                .single()
+                .unwrap_or_else(chrono::Utc::now),
+            size: status.length,
+        }
+    }
+
+    fn list_recursive(
+        self: Arc<Self>,
+        prefix: Path,
+    ) -> BoxStream<'static, ObjectStoreResult<ObjectMeta>> {
+        stream::once(async move {
+            let children = self.list_status(&prefix).await?;
+            let mut files = vec![];
+            let mut dirs = vec![];
+            for status in children {
+                if status.file_type == "DIRECTORY" {
+                    dirs.push(prefix.child(status.path_suffix.as_str()));
+                } else {
+                    files.push(self.child_meta(&prefix, &status));
+                }
+            }
+            let nested = stream::iter(dirs).flat_map(move |dir| self.clone().list_recursive(dir));
+            Ok::<_, ObjectStoreError>(
+                stream::iter(files.into_iter().map(Ok))
+                    .chain(nested)
+                    .boxed(),
+            )
+        })
+        .try_flatten()
+        .boxed()
+    }
+}
+
+use chrono::TimeZone;
+
+/// WebHDFS reports errors as a JSON body with an HTTP status that maps
+/// fairly directly onto the exceptions this binding already understands.
+async fn check_status(
+    response: reqwest::Response,
+    location: &Path,
+) -> ObjectStoreResult<reqwest::Response> {
+    match response.status() {
+        StatusCode::OK | StatusCode::CREATED | StatusCode::TEMPORARY_REDIRECT => Ok(response),
+        StatusCode::NOT_FOUND => Err(ObjectStoreError::NotFound {
+            path: location.to_string(),
+            source: generic(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "WebHDFS FileNotFoundException",
+            ))
+            .into(),
+        }),
+        StatusCode::CONFLICT => Err(ObjectStoreError::AlreadyExists {
+            path: location.to_string(),
+            source: generic(std::io::Error::new(
+                std::io::ErrorKind::AlreadyExists,
+                "WebHDFS FileAlreadyExistsException",
+            ))
+            .into(),
+        }),
+        status => {
+            let body = response.text().await.unwrap_or_default();
+            Err(generic(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("WebHDFS request failed with {status}: {body}"),
+            )))
+        }
+    }
+}
+
+enum UploadMsg {
+    Chunk(Bytes),
+    Shutdown(oneshot::Sender<std::io::Result<()>>),
+}
+
+/// A naive multipart writer: WebHDFS has no concept of independently
+/// addressed parts, so writes are buffered and flushed as a single
+/// `CREATE` when the stream is shut down; `APPEND` is not used.
+struct WebHdfsUpload {
+    tx: mpsc::UnboundedSender<UploadMsg>,
+    shutdown: Option<oneshot::Receiver<std::io::Result<()>>>,
+}
+
+impl WebHdfsUpload {
+    fn new(store: WebHdfs, location: Path) -> Self {
+        let (tx, mut rx) = mpsc::unbounded_channel::<UploadMsg>();
+        tokio::spawn(async move {
+            let mut buffer = Vec::new();
+            while let Some(msg) = rx.recv().await {
+                match msg {
+                    UploadMsg::Chunk(bytes) => buffer.extend_from_slice(&bytes),
+                    UploadMsg::Shutdown(done) => {
+                        let result = store
+                            .put(&location, Bytes::from(buffer))
+                            .await
+                            .map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err));
+                        let _ = done.send(result);
+                        return;
+                    }
+                }
+            }
+        });
+        Self { tx, shutdown: None }
+    }
+}
+
+impl AsyncWrite for WebHdfsUpload {
+    fn poll_write(
+        self: std::pin::Pin<&mut Self>,
+        _cx: &mut std::task::Context<'_>,
+        buf: &[u8],
+    ) -> std::task::Poll<std::io::Result<usize>> {
+        let _ = self.tx.send(UploadMsg::Chunk(Bytes::copy_from_slice(buf)));
+        std::task::Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(
+        self: std::pin::Pin<&mut Self>,
+        _cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        std::task::Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        if self.shutdown.is_none() {
+            let (tx, rx) = oneshot::channel();
+            let _ = self.tx.send(UploadMsg::Shutdown(tx));
+            self.shutdown = Some(rx);
+        }
+        match self.shutdown.as_mut().unwrap().try_recv() {
+            Ok(result) => std::task::Poll::Ready(result),
+            Err(oneshot::error::TryRecvError::Empty) => {
+                cx.waker().wake_by_ref();
+                std::task::Poll::Pending
+            }
+            Err(oneshot::error::TryRecvError::Closed) => std::task::Poll::Ready(Ok(())),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ObjectStore for WebHdfs {
+    async fn put(&self, location: &Path, bytes: Bytes) -> ObjectStoreResult<()> {
+        self.redirected_write(
+            location,
+            "CREATE",
+            &[("overwrite", "true".to_string())],
+            bytes,
+        )
+        .await
+    }
+
+    async fn put_multipart(
+        &self,
+        location: &Path,
+    ) -> ObjectStoreResult<(MultipartId, Box<dyn AsyncWrite + Unpin + Send>)> {
+        let id = uuid::Uuid::new_v4().to_string();
+        Ok((
+            id,
+            Box::new(WebHdfsUpload::new(self.clone(), location.clone())),
+        ))
+    }
+
+    async fn abort_multipart(
+        &self,
+        location: &Path,
+        _multipart_id: &MultipartId,
+    ) -> ObjectStoreResult<()> {
+        // Nothing was ever committed server-side for an aborted buffer, but
+        // a prior partial `CREATE` (if any) shouldn't be left behind.
+        match self.delete(location).await {
+            Ok(()) | Err(ObjectStoreError::NotFound { .. }) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn get_opts(&self, location: &Path, options: GetOptions) -> ObjectStoreResult<GetResult> {
+        let meta = self.head(location).await?;
+        let range = options.range.unwrap_or(0..meta.size);
+        let bytes = self.get_range(location, range.clone()).await?;
+        Ok(GetResult {
+            payload: GetResultPayload::Stream(stream::once(async move { Ok(bytes) }).boxed()),
+            meta,
+            range,
+        })
+    }
+
+    async fn get(&self, location: &Path) -> ObjectStoreResult<GetResult> {
+        self.get_opts(location, GetOptions::default()).await
+    }
+
+    async fn get_range(&self, location: &Path, range: Range<usize>) -> ObjectStoreResult<Bytes> {
+        let extra = [
+            ("offset", range.start.to_string()),
+            ("length", (range.end - range.start).to_string()),
+        ];
+        let url = self.request_url(location, "OPEN", &extra);
+        let probe = self
+            .authenticate(self.client.get(url))
+            .send()
+            .await
+            .map_err(generic)?;
+        // `OPEN` 307-redirects to the datanode that actually holds the
+        // block, the same two-step dance `redirected_write` does for
+        // CREATE/APPEND -- remap the host before following it rather than
+        // letting reqwest auto-follow straight to a possibly-unreachable
+        // internal hostname.
+        let response = match probe.headers().get(reqwest::header::LOCATION) {
+            Some(location_header) => {
+                let redirect = location_header.to_str().map_err(generic)?;
+                let datanode_url = self.remap_redirect(Url::parse(redirect).map_err(generic)?);
+                self.authenticate(self.client.get(datanode_url))
+                    .send()
+                    .await
+                    .map_err(generic)?
+            }
+            None => probe,
+        };
+        let response = check_status(response, location).await?;
+        response.bytes().await.map_err(generic)
+    }
+
+    async fn head(&self, location: &Path) -> ObjectStoreResult<ObjectMeta> {
+        let url = self.request_url(location, "GETFILESTATUS", &[]);
+        let response = self
+            .authenticate(self.client.get(url))
+            .send()
+            .await
+            .map_err(generic)?;
+        let response = check_status(response, location).await?;
+        let parsed: GetFileStatusResponse = response.json().await.map_err(generic)?;
+        Ok(ObjectMeta {
+            location: location.clone(),
+            last_modified: chrono::Utc
+                .timestamp_millis_opt(parsed.file_status.modification_time)
+                .single()
+                .unwrap_or_else(chrono::Utc::now),
+            size: parsed.file_status.length,
+        })
+    }
+
+    async fn delete(&self, location: &Path) -> ObjectStoreResult<()> {
+        let url = self.request_url(location, "DELETE", &[("recursive", "false".to_string())]);
+        let response = self
+            .authenticate(self.client.delete(url))
+            .send()
+            .await
+            .map_err(generic)?;
+        check_status(response, location).await?;
+        Ok(())
+    }
+
+    async fn list(
+        &self,
+        prefix: Option<&Path>,
+    ) -> ObjectStoreResult<BoxStream<'_, ObjectStoreResult<ObjectMeta>>> {
+        let root = prefix.cloned().unwrap_or_else(|| Path::from(""));
+        Ok(Arc::new(self.clone()).list_recursive(root))
+    }
+
+    async fn list_with_delimiter(&self, prefix: Option<&Path>) -> ObjectStoreResult<ListResult> {
+        let root = prefix.cloned().unwrap_or_else(|| Path::from(""));
+        let children = self.list_status(&root).await?;
+        let mut common_prefixes = vec![];
+        let mut objects = vec![];
+        for status in children {
+            if status.file_type == "DIRECTORY" {
+                common_prefixes.push(root.child(status.path_suffix.as_str()));
+            } else {
+                objects.push(self.child_meta(&root, &status));
+            }
+        }
+        Ok(ListResult {
+            common_prefixes,
+            objects,
+        })
+    }
+
+    async fn copy(&self, from: &Path, to: &Path) -> ObjectStoreResult<()> {
+        // WebHDFS has no server-side copy; fetch the source and re-upload.
+        let bytes = self.get(from).await?.bytes().await?;
+        self.put(to, bytes).await
+    }
+
+    async fn copy_if_not_exists(&self, from: &Path, to: &Path) -> ObjectStoreResult<()> {
+        match self.head(to).await {
+            Ok(_) => Err(ObjectStoreError::AlreadyExists {
+                path: to.to_string(),
+                source: generic(std::io::Error::new(
+                    std::io::ErrorKind::AlreadyExists,
+                    "destination already exists",
+                ))
+                .into(),
+            }),
+            Err(ObjectStoreError::NotFound { .. }) => self.copy(from, to).await,
+            Err(err) => Err(err),
+        }
+    }
+}
@@ -1,20 +1,32 @@
 use object_store_internal::{
-    ArrowFileSystemHandler, ObjectInputFile, ObjectOutputStream, PyClientOptions, PyListResult,
-    PyObjectMeta, PyObjectStore, PyPath,
+    sync, sync_async, ArrowFileSystemHandler, NotModified, ObjectInputFile, ObjectOutputStream,
+    PyClientOptions, PyGetResult, PyListResult, PyMultipartUpload, PyObjectMeta, PyObjectStore,
+    PyPath, PySyncSummary,
 };
 use pyo3::prelude::*;
+use pyo3::wrap_pyfunction;
 
 #[pymodule]
-fn _internal(_py: Python, m: &PyModule) -> PyResult<()> {
+fn _internal(py: Python, m: &PyModule) -> PyResult<()> {
     // Register the python classes
     m.add_class::<PyClientOptions>()?;
     m.add_class::<PyObjectStore>()?;
     m.add_class::<PyPath>()?;
     m.add_class::<PyObjectMeta>()?;
     m.add_class::<PyListResult>()?;
+    m.add_class::<PyGetResult>()?;
+    m.add_class::<PyMultipartUpload>()?;
+    m.add_class::<PySyncSummary>()?;
     m.add_class::<ArrowFileSystemHandler>()?;
     m.add_class::<ObjectInputFile>()?;
     m.add_class::<ObjectOutputStream>()?;
 
+    // Register module-level functions
+    m.add_function(wrap_pyfunction!(sync, m)?)?;
+    m.add_function(wrap_pyfunction!(sync_async, m)?)?;
+
+    // Register exceptions
+    m.add("NotModified", py.get_type::<NotModified>())?;
+
     Ok(())
 }